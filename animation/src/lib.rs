@@ -0,0 +1,211 @@
+/*
+    Step-by-step playback of the simulations of the days, see `terminal::play` for the terminal front end
+*/
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::time::Duration;
+
+#[cfg(feature = "terminal")]
+pub mod terminal;
+
+/// Picture of a simulation at one step, along with a caption describing the step
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    pub picture: String,
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>, picture: impl Display) -> Self {
+        Frame {
+            caption: caption.into(),
+            picture: picture.to_string(),
+        }
+    }
+}
+
+/// Request of the user to the player
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Control {
+    TogglePlay,
+    Step,
+    Back,
+    Faster,
+    Slower,
+    Quit,
+}
+
+/// Most recent frames kept for stepping back, older ones are dropped
+const HISTORY: usize = 1000;
+const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+const MIN_DELAY: Duration = Duration::from_millis(1);
+const MAX_DELAY: Duration = Duration::from_secs(2);
+
+/// Position in a sequence of frames, pulled from the iterator only when reached, so that endless
+/// simulations can be played
+pub struct Player<I> {
+    frames: I,
+    history: VecDeque<Frame>,
+    // Index of the first frame of 'history' in the whole sequence
+    first: usize,
+    // Index of the shown frame in 'history'
+    current: usize,
+    finished: bool,
+    playing: bool,
+    delay: Duration,
+}
+
+impl<I: Iterator<Item = Frame>> Player<I> {
+    /// Starts paused on the first frame
+    pub fn new(frames: impl IntoIterator<IntoIter = I>) -> Self {
+        let mut frames = frames.into_iter();
+        let history: VecDeque<Frame> = frames.next().into_iter().collect();
+        Player {
+            frames,
+            finished: history.is_empty(),
+            history,
+            first: 0,
+            current: 0,
+            playing: false,
+            delay: DEFAULT_DELAY,
+        }
+    }
+
+    /// Shown frame, none if there are no frames at all
+    pub fn frame(&self) -> Option<&Frame> {
+        self.history.get(self.current)
+    }
+
+    /// Index of the shown frame in the whole sequence
+    pub fn index(&self) -> usize {
+        self.first + self.current
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Time between two frames while playing
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Shows the next frame, returns false and pauses when the shown frame is the last one
+    pub fn advance(&mut self) -> bool {
+        if self.current + 1 < self.history.len() {
+            self.current += 1;
+            return true;
+        }
+        let next = if self.finished {
+            None
+        } else {
+            self.frames.next()
+        };
+        match next {
+            Some(frame) => {
+                self.history.push_back(frame);
+                if self.history.len() > HISTORY {
+                    self.history.pop_front();
+                    self.first += 1;
+                } else {
+                    self.current += 1;
+                }
+                true
+            }
+            None => {
+                self.finished = true;
+                self.playing = false;
+                false
+            }
+        }
+    }
+
+    /// Applies 'control', returns false once the user asked to quit
+    pub fn control(&mut self, control: Control) -> bool {
+        match control {
+            Control::TogglePlay => self.playing = !self.playing,
+            Control::Step => {
+                self.playing = false;
+                self.advance();
+            }
+            Control::Back => {
+                self.playing = false;
+                self.current = self.current.saturating_sub(1);
+            }
+            Control::Faster => self.delay = (self.delay / 2).max(MIN_DELAY),
+            Control::Slower => self.delay = (self.delay * 2).min(MAX_DELAY),
+            Control::Quit => return false,
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frames(count: usize) -> impl Iterator<Item = Frame> {
+        (0..count).map(|i| Frame::new(format!("step {}", i), i))
+    }
+
+    #[test]
+    fn steps_forward_and_back() {
+        let mut player = Player::new(frames(3));
+        assert_eq!(player.frame().unwrap().caption, "step 0");
+
+        assert!(player.control(Control::Step));
+        assert!(player.control(Control::Step));
+        assert_eq!(player.frame().unwrap().picture, "2");
+        player.control(Control::Step);
+        assert_eq!(player.index(), 2);
+
+        player.control(Control::Back);
+        player.control(Control::Back);
+        player.control(Control::Back);
+        assert_eq!(player.index(), 0);
+        assert!(player.advance());
+        assert_eq!(player.frame().unwrap().caption, "step 1");
+    }
+
+    #[test]
+    fn pauses_at_the_end() {
+        let mut player = Player::new(frames(2));
+        player.control(Control::TogglePlay);
+        assert!(player.is_playing());
+
+        assert!(player.advance());
+        assert!(!player.advance());
+        assert!(!player.is_playing());
+        assert_eq!(player.index(), 1);
+        assert!(!player.control(Control::Quit));
+
+        assert_eq!(Player::new(frames(0)).frame(), None);
+    }
+
+    #[test]
+    fn plays_endless_simulations() {
+        let mut player = Player::new((0..).map(|i| Frame::new("", i)));
+        for _ in 0..HISTORY + 10 {
+            assert!(player.advance());
+        }
+        assert_eq!(player.index(), HISTORY + 10);
+        assert_eq!(player.frame().unwrap().picture, (HISTORY + 10).to_string());
+
+        // Only the most recent frames can be stepped back to
+        for _ in 0..HISTORY + 10 {
+            player.control(Control::Back);
+        }
+        assert_eq!(player.index(), 11);
+    }
+
+    #[test]
+    fn changes_speed_within_bounds() {
+        let mut player = Player::new(frames(1));
+        player.control(Control::Faster);
+        assert_eq!(player.delay(), DEFAULT_DELAY / 2);
+        (0..20).for_each(|_| _ = player.control(Control::Faster));
+        assert_eq!(player.delay(), MIN_DELAY);
+        (0..20).for_each(|_| _ = player.control(Control::Slower));
+        assert_eq!(player.delay(), MAX_DELAY);
+    }
+}
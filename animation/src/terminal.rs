@@ -0,0 +1,106 @@
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::{cursor, execute, queue, style, terminal};
+
+use crate::{Control, Frame, Player};
+
+const HELP: &str = "space: play/pause  right/n: step  left/p: back  +/-: speed  q: quit";
+
+/// Plays 'frames' full screen until the user quits, starting paused on the first frame
+pub fn play(frames: impl IntoIterator<Item = Frame>) -> io::Result<()> {
+    let mut player = Player::new(frames);
+    let mut stdout = io::stdout();
+    let _screen = Screen::enter(&mut stdout)?;
+
+    let mut next_frame = Instant::now() + player.delay();
+    loop {
+        draw(&mut stdout, &player)?;
+
+        // Wait for a key until the next frame is due, or indefinitely while paused
+        let timeout = if player.is_playing() {
+            next_frame.saturating_duration_since(Instant::now())
+        } else {
+            Duration::from_secs(3600)
+        };
+        if event::poll(timeout)? {
+            if let Some(control) = control(event::read()?) {
+                if !player.control(control) {
+                    return Ok(());
+                }
+                next_frame = Instant::now() + player.delay();
+            }
+        } else if player.is_playing() {
+            player.advance();
+            next_frame += player.delay();
+        }
+    }
+}
+
+/// Control bound to a key press, if any
+fn control(event: Event) -> Option<Control> {
+    let Event::Key(key) = event else {
+        return None;
+    };
+    if key.kind != KeyEventKind::Press {
+        return None;
+    }
+    match key.code {
+        KeyCode::Char(' ') => Some(Control::TogglePlay),
+        KeyCode::Right | KeyCode::Char('n') => Some(Control::Step),
+        KeyCode::Left | KeyCode::Char('p') => Some(Control::Back),
+        KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Up => Some(Control::Faster),
+        KeyCode::Char('-') | KeyCode::Down => Some(Control::Slower),
+        KeyCode::Char('q') | KeyCode::Esc => Some(Control::Quit),
+        _ => None,
+    }
+}
+
+/// Draws the status line, the caption and as much of the picture as fits in the terminal
+fn draw<I: Iterator<Item = Frame>>(out: &mut impl Write, player: &Player<I>) -> io::Result<()> {
+    let (width, height) = terminal::size()?;
+    let status = format!(
+        "frame {}  {}  {} ms/frame",
+        player.index(),
+        if player.is_playing() {
+            "playing"
+        } else {
+            "paused"
+        },
+        player.delay().as_millis()
+    );
+    let (caption, picture) = match player.frame() {
+        Some(frame) => (frame.caption.as_str(), frame.picture.as_str()),
+        None => ("no frames", ""),
+    };
+
+    queue!(out, terminal::Clear(terminal::ClearType::All))?;
+    let lines = [status.as_str(), HELP, caption, ""]
+        .into_iter()
+        .chain(picture.lines());
+    for (row, line) in lines.take(height as usize).enumerate() {
+        let line: String = line.chars().take(width as usize).collect();
+        queue!(out, cursor::MoveTo(0, row as u16), style::Print(line))?;
+    }
+    out.flush()
+}
+
+/// Raw mode on the alternate screen, restored when dropped, even on errors
+struct Screen;
+
+impl Screen {
+    fn enter(out: &mut impl Write) -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        let screen = Screen;
+        execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
@@ -0,0 +1,109 @@
+/*
+    Times the parse step and both parts of every day on the real inputs, and how some days scale on
+    generated inputs of growing size
+*/
+use std::error::Error;
+use std::hint::black_box;
+use std::io::Read;
+
+use common::error;
+use common::input::{self, DayFiles, InputArgs};
+use common::solution::Entry;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Benchmarks parse, part one and part two of every day, each in a group named after the day crate,
+/// with the parameters of `aoc.toml`
+fn bench_all(c: &mut Criterion) {
+    for entry in registry::entries(None) {
+        if let Err(e) = bench_day(c, entry) {
+            eprintln!("Skipping {}: {}", entry.files.name, e);
+        }
+    }
+}
+
+fn bench_day(c: &mut Criterion, entry: &Entry) -> Result<(), Box<dyn Error>> {
+    // Read the input once, so that only parsing is timed and not the disk
+    let mut text = Vec::new();
+    input::real(entry.files)?.read_to_end(&mut text)?;
+    let solution = entry.load(&InputArgs::default())?;
+    let parsed = solution.parse(&mut text.as_slice())?;
+
+    let mut group = c.benchmark_group(entry.files.name);
+    group.bench_function("parse", |b| {
+        b.iter(|| solution.parse(&mut black_box(text.as_slice())))
+    });
+    group.bench_function("part_one", |b| b.iter(|| black_box(&parsed).part_one()));
+    group.bench_function("part_two", |b| b.iter(|| black_box(&parsed).part_two()));
+    group.finish();
+    Ok(())
+}
+
+/// Benchmarks part one and part two of a day on inputs generated for each of 'sizes', in a group
+/// named after the day crate followed by `_scaling`
+fn bench_scaling<I, A, B>(
+    c: &mut Criterion,
+    files: &DayFiles,
+    sizes: &[usize],
+    generate: impl Fn(usize) -> String,
+    parse: impl Fn(&[u8]) -> error::Result<I>,
+    part_one: impl Fn(&I) -> A,
+    part_two: impl Fn(&I) -> B,
+) {
+    let mut group = c.benchmark_group(format!("{}_scaling", files.name));
+    for &size in sizes {
+        let input = parse(generate(size).as_bytes()).expect("Generated inputs are valid");
+        group.bench_with_input(BenchmarkId::new("part_one", size), &input, |b, input| {
+            b.iter(|| part_one(black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("part_two", size), &input, |b, input| {
+            b.iter(|| part_two(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_generated(c: &mut Criterion) {
+    // Number of files on the disk
+    bench_scaling(
+        c,
+        &y2024_day09::FILES,
+        &[1_000, 10_000, 100_000],
+        |files| y2024_day09::generate::input(9, files),
+        |text| y2024_day09::parse(text),
+        |input| y2024_day09::part_one(input),
+        |input| y2024_day09::part_two(input),
+    );
+    // Side of the square garden
+    bench_scaling(
+        c,
+        &y2024_day12::FILES,
+        &[50, 100, 200],
+        |side| y2024_day12::generate::input(12, side, side, 26),
+        |text| y2024_day12::parse(text),
+        y2024_day12::part_one,
+        y2024_day12::part_two,
+    );
+    // Number of robots, with a tree so that part two ends
+    bench_scaling(
+        c,
+        &y2024_day14::FILES,
+        &[100, 500, 2_500],
+        |robots| y2024_day14::generate::input(14, robots, 101, 103, Some(1_000)),
+        |text| y2024_day14::parse(text),
+        |input| y2024_day14::part_one(input, &Default::default()),
+        |input| y2024_day14::part_two(input, &Default::default()),
+    );
+    // Side of the square warehouse, with ten moves per cell
+    bench_scaling(
+        c,
+        &y2024_day15::FILES,
+        &[20, 50, 100],
+        |side| y2024_day15::generate::input(15, side, side, 10 * side * side),
+        |text| y2024_day15::parse(text),
+        y2024_day15::part_one,
+        y2024_day15::part_two,
+    );
+}
+
+criterion_group!(benches, bench_all, bench_generated);
+criterion_main!(benches);
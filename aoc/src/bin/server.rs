@@ -0,0 +1,386 @@
+/*
+    Answers puzzles sent over HTTP: `POST /{year}/day/{n}/part/{p}` with the input as body, or
+    `POST /day/{n}/part/{p}` for 2024
+*/
+use std::env;
+use std::error::Error;
+use std::io::{self, Read, Write};
+use std::net::Ipv4Addr;
+use std::path::PathBuf;
+use std::process::{Command, ExitCode, Output, Stdio};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use clap::Parser;
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+/// Interval between two checks of a running solution
+const POLL: Duration = Duration::from_millis(10);
+
+/// Year of the paths without one, which the server answered before it handled other years
+const DEFAULT_YEAR: &str = "2024";
+
+#[derive(Parser)]
+#[command(
+    name = "server",
+    about = "Serves the Advent of Code solutions on localhost"
+)]
+struct Cli {
+    /// Port listened to on 127.0.0.1
+    #[arg(long, default_value_t = 8024)]
+    port: u16,
+
+    /// Largest accepted input, in bytes
+    #[arg(long, value_name = "BYTES", default_value_t = 1 << 20)]
+    max_body: u64,
+
+    /// Time after which a solution is stopped, in seconds
+    #[arg(long, value_name = "SECONDS", default_value_t = 10.0)]
+    timeout: f64,
+
+    /// Number of requests handled at the same time
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u16).range(1..))]
+    workers: u16,
+
+    /// Runner binary solving the puzzles [default: the aoc binary next to this one]
+    #[arg(long, value_name = "PATH")]
+    runner: Option<PathBuf>,
+}
+
+/// Limits applied to every request
+struct Settings {
+    runner: PathBuf,
+    max_body: u64,
+    timeout: Duration,
+}
+
+/// Response to a request, as a status code and a JSON body
+#[derive(Debug, PartialEq)]
+struct Reply {
+    status: u16,
+    body: Value,
+}
+
+impl Reply {
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Reply {
+            status,
+            body: json!({ "error": message.into() }),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    common::trace::init();
+    common::error::report(run())
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let cli = Cli::parse();
+    let runner = match cli.runner {
+        Some(runner) => runner,
+        None => env::current_exe()?.with_file_name(format!("aoc{}", env::consts::EXE_SUFFIX)),
+    };
+    if !runner.is_file() {
+        return Err(format!(
+            "Cannot find the runner {}, build it with `cargo build --release -p aoc` or give --runner",
+            runner.display()
+        )
+        .into());
+    }
+    let settings = Arc::new(Settings {
+        runner,
+        max_body: cli.max_body,
+        timeout: Duration::try_from_secs_f64(cli.timeout)
+            .map_err(|e| format!("Invalid --timeout: {}", e))?,
+    });
+
+    // Only reachable from this machine, the solutions are not meant to face untrusted clients
+    let server = Server::http((Ipv4Addr::LOCALHOST, cli.port))
+        .map_err(|e| format!("Cannot listen on port {}: {}", cli.port, e))?;
+    let server = Arc::new(server);
+    println!("Listening on http://127.0.0.1:{}", cli.port);
+
+    let workers: Vec<_> = (0..cli.workers)
+        .map(|_| {
+            let server = Arc::clone(&server);
+            let settings = Arc::clone(&settings);
+            thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    respond(request, &settings);
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker.join().map_err(|_| "A worker panicked")?;
+    }
+    Ok(())
+}
+
+/// Answers 'request' and logs it
+fn respond(mut request: Request, settings: &Settings) {
+    let start = Instant::now();
+    let reply = handle(&mut request, settings);
+    let line = format!("{} {}", request.method(), request.url());
+    eprintln!(
+        "{} {} {:.3} ms",
+        line,
+        reply.status,
+        common::output::as_ms(start.elapsed())
+    );
+
+    let header =
+        Header::from_bytes("Content-Type", "application/json").expect("The header is valid ASCII");
+    let response = Response::from_string(reply.body.to_string())
+        .with_status_code(reply.status)
+        .with_header(header);
+    if let Err(e) = request.respond(response) {
+        eprintln!("Cannot answer {}: {}", line, e);
+    }
+}
+
+fn handle(request: &mut Request, settings: &Settings) -> Reply {
+    if *request.method() == Method::Get && request.url().split('?').next() == Some("/") {
+        return index();
+    }
+    let (year, day, part) = match route(request.method(), request.url()) {
+        Ok(route) => route,
+        Err(reply) => return reply,
+    };
+
+    let too_large = || {
+        Reply::error(
+            413,
+            format!("The input is larger than {} bytes", settings.max_body),
+        )
+    };
+    if request
+        .body_length()
+        .is_some_and(|length| length as u64 > settings.max_body)
+    {
+        return too_large();
+    }
+    // The length is not known in advance for chunked bodies
+    let mut input = Vec::new();
+    if let Err(e) = request
+        .as_reader()
+        .take(settings.max_body + 1)
+        .read_to_end(&mut input)
+    {
+        return Reply::error(400, format!("Cannot read the input: {}", e));
+    }
+    if input.len() as u64 > settings.max_body {
+        return too_large();
+    }
+
+    solve(settings, year, day, part, input)
+}
+
+/// Year, day and part of a `POST /{year}/day/{n}/part/{p}` request
+fn route(method: &Method, url: &str) -> Result<(u16, u8, u8), Reply> {
+    let path = url.split('?').next().unwrap_or_default();
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let (year, day, part) = match segments[..] {
+        [year, "day", day, "part", part] => (year, day, part),
+        ["day", day, "part", part] => (DEFAULT_YEAR, day, part),
+        _ => {
+            return Err(Reply::error(
+                404,
+                format!(
+                    "Unknown path {}, try POST /{{year}}/day/{{n}}/part/{{p}}",
+                    path
+                ),
+            ))
+        }
+    };
+    if *method != Method::Post {
+        return Err(Reply::error(
+            405,
+            format!("Use POST to solve a puzzle, not {}", method),
+        ));
+    }
+    let year = year
+        .parse()
+        .map_err(|_| Reply::error(404, format!("Invalid year '{}'", year)))?;
+    let day = day
+        .parse()
+        .map_err(|_| Reply::error(404, format!("Invalid day '{}'", day)))?;
+    let part = match part.parse() {
+        Ok(part @ 1..=2) => part,
+        _ => return Err(Reply::error(404, format!("Part {} does not exist", part))),
+    };
+    if registry::get(year, day).is_none() {
+        return Err(Reply::error(
+            404,
+            format!("Day {} of {} is not implemented", day, year),
+        ));
+    }
+    Ok((year, day, part))
+}
+
+/// Implemented days of every year, as `{"2024": [1, 2, ...]}`
+fn index() -> Reply {
+    let years = registry::YEARS
+        .iter()
+        .map(|(year, days)| {
+            let days: Vec<u8> = days.iter().map(|entry| entry.files.day).collect();
+            (year.to_string(), json!(days))
+        })
+        .collect();
+    Reply {
+        status: 200,
+        body: Value::Object(years),
+    }
+}
+
+/// Runs the solution in a child process of the runner, which can be killed when it takes too long
+fn solve(settings: &Settings, year: u16, day: u8, part: u8, input: Vec<u8>) -> Reply {
+    let mut command = Command::new(&settings.runner);
+    command.args(["run", &year.to_string(), &day.to_string()]);
+    command.args(["--part", &part.to_string()]);
+    command.args(["--input", "-", "--format", "json"]);
+
+    let output = match run_with_timeout(command, input, settings.timeout) {
+        Ok(Some(output)) => output,
+        Ok(None) => {
+            return Reply::error(
+                504,
+                format!(
+                    "Day {} of {} part {} did not finish within {:?}",
+                    day, year, part, settings.timeout
+                ),
+            )
+        }
+        Err(e) => return Reply::error(500, format!("Cannot run the solution: {}", e)),
+    };
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let prefix = format!("Error: {} day {}: ", year, day);
+        let message = stderr.trim_end();
+        return Reply::error(422, message.strip_prefix(&prefix).unwrap_or(message));
+    }
+    record(&output.stdout)
+}
+
+/// Reply made of the JSON record printed by the runner
+fn record(stdout: &[u8]) -> Reply {
+    let stdout = String::from_utf8_lossy(stdout);
+    match serde_json::from_str(stdout.trim()) {
+        Ok(body) => Reply { status: 200, body },
+        Err(e) => Reply::error(500, format!("Unexpected output '{}': {}", stdout.trim(), e)),
+    }
+}
+
+/// Runs 'command' with 'input' on its stdin, killing it after 'timeout', in which case there is no
+/// output
+fn run_with_timeout(
+    mut command: Command,
+    input: Vec<u8>,
+    timeout: Duration,
+) -> io::Result<Option<Output>> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // The pipes are served by threads, so that a large input or output never blocks the child
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let writer = thread::spawn(move || stdin.write_all(&input));
+    let stdout = read_in_background(child.stdout.take().expect("stdout is piped"));
+    let stderr = read_in_background(child.stderr.take().expect("stderr is piped"));
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(POLL);
+    };
+
+    // A child exiting early may not read all its input, which is not an error of the server
+    let _ = writer.join();
+    Ok(Some(Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    }))
+}
+
+fn read_in_background(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        let _ = pipe.read_to_end(&mut bytes);
+        bytes
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routes_the_puzzles() {
+        assert_eq!(route(&Method::Post, "/2024/day/6/part/2"), Ok((2024, 6, 2)));
+        assert_eq!(
+            route(&Method::Post, "/2024/day/14/part/1/?debug"),
+            Ok((2024, 14, 1))
+        );
+        // The path without a year is kept for 2024
+        assert_eq!(route(&Method::Post, "/day/6/part/2"), Ok((2024, 6, 2)));
+
+        let status = |method: Method, url: &str| route(&method, url).unwrap_err().status;
+        assert_eq!(status(Method::Get, "/2024/day/6/part/2"), 405);
+        assert_eq!(status(Method::Post, "/day/6/part/3"), 404);
+        assert_eq!(status(Method::Post, "/day/6"), 404);
+        assert_eq!(status(Method::Post, "/2024/day/six/part/1"), 404);
+        assert_eq!(status(Method::Post, "/y2024/day/6/part/1"), 404);
+        assert_eq!(status(Method::Post, "/2024/day/6/part/3"), 404);
+        assert_eq!(status(Method::Post, "/2024/day/16/part/1"), 404);
+        assert_eq!(status(Method::Post, "/2014/day/1/part/1"), 404);
+    }
+
+    #[test]
+    fn lists_the_days() {
+        let reply = index();
+        assert_eq!(reply.status, 200);
+        assert_eq!(reply.body["2024"][0], 1);
+        assert_eq!(reply.body["2024"][14], 15);
+    }
+
+    #[test]
+    fn passes_the_records_through() {
+        let reply =
+            record(b"{\"year\":2024,\"day\":1,\"part\":1,\"answer\":\"11\",\"parse_ms\":0.1,\"solve_ms\":0.2}\n");
+        assert_eq!(reply.status, 200);
+        assert_eq!(reply.body["answer"], "11");
+        assert_eq!(record(b"Result (part one): 11\n").status, 500);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn stops_slow_solutions() {
+        let mut echo = Command::new("sh");
+        echo.args(["-c", "cat"]);
+        let output = run_with_timeout(echo, b"3   4\n".to_vec(), Duration::from_secs(10))
+            .unwrap()
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(output.stdout, b"3   4\n");
+
+        let mut endless = Command::new("sleep");
+        endless.arg("10");
+        let start = Instant::now();
+        let output = run_with_timeout(endless, Vec::new(), Duration::from_millis(100)).unwrap();
+        assert!(output.is_none());
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,226 @@
+use std::error::Error;
+use std::io::{BufRead, Cursor, Read};
+use std::time::{Duration, Instant};
+
+use common::error;
+use common::input::{self, DayFiles, InputArgs};
+use common::ledger;
+use common::output::{Allocations, Record};
+use common::solution::AnySolution;
+use tracing::{info_span, Span};
+
+use crate::profile;
+
+mod y2024;
+
+/// Puzzle of the runner, as its year and day
+pub type Puzzle = (u16, u8);
+
+/// Implemented days of 'year', or of every year
+pub fn puzzles(year: Option<u16>) -> Vec<Puzzle> {
+    registry::entries(year)
+        .map(|entry| (entry.files.year, entry.files.day))
+        .collect()
+}
+
+/// Selects which parts of a day should be solved
+#[derive(Clone, Copy)]
+pub struct Parts {
+    pub one: bool,
+    pub two: bool,
+}
+
+impl Parts {
+    pub fn new(part: Option<u8>) -> Self {
+        Parts {
+            one: part.is_none_or(|p| p == 1),
+            two: part.is_none_or(|p| p == 2),
+        }
+    }
+}
+
+/// Input files of the day, if implemented
+pub fn files(year: u16, day: u8) -> Option<&'static DayFiles> {
+    registry::get(year, day).map(|entry| entry.files)
+}
+
+/// Checks the structure of an input of the day, returning every problem found, if the day is
+/// implemented.
+///
+/// The parameters of the day, such as the area of day fourteen, are read from the config file
+/// selected by 'args'.
+pub fn check(
+    year: u16,
+    day: u8,
+    mut reader: Box<dyn BufRead>,
+    args: &InputArgs,
+) -> Option<Vec<error::Error>> {
+    let entry = registry::get(year, day)?;
+    Some(match entry.load(args) {
+        Ok(solution) => solution.check(&mut reader),
+        Err(e) => vec![error::Error::invalid(entry.files.name, e.to_string())],
+    })
+}
+
+/// Plays the simulation of the input day step by step in the terminal
+pub fn animate(year: u16, day: u8, input: &InputArgs, part: u8) -> Result<(), Box<dyn Error>> {
+    match year {
+        2024 => y2024::animate(day, input, part),
+        _ => Err(format!("{} has no animation", year).into()),
+    }
+}
+
+/// Answer of a selected part, or the error returned while solving it
+pub type Part = (u8, Result<Record, String>);
+
+/// Parts of each day, or the error returned before solving them, in the order of 'puzzles'
+pub type Results = Vec<(Puzzle, Result<Vec<Part>, String>)>;
+
+/// Runs 'puzzles' one after the other
+pub fn run_each(puzzles: Vec<Puzzle>, input: &InputArgs, parts: Parts) -> Results {
+    puzzles
+        .into_iter()
+        .map(|(year, day)| {
+            let result = run(year, day, input, parts).map_err(|e| e.to_string());
+            ((year, day), result)
+        })
+        .collect()
+}
+
+/// Runs 'puzzles', one after the other without the `parallel` feature
+#[cfg(not(feature = "parallel"))]
+pub fn run_all(puzzles: Vec<Puzzle>, input: &InputArgs, parts: Parts) -> Results {
+    run_each(puzzles, input, parts)
+}
+
+/// Runs 'puzzles' concurrently, each day measures its own time, but shares the cores with the others
+#[cfg(feature = "parallel")]
+pub fn run_all(puzzles: Vec<Puzzle>, input: &InputArgs, parts: Parts) -> Results {
+    use rayon::prelude::*;
+
+    puzzles
+        .into_par_iter()
+        .map(|(year, day)| {
+            let result = run(year, day, input, parts).map_err(|e| e.to_string());
+            ((year, day), result)
+        })
+        .collect()
+}
+
+/// Runs the selected parts of the input day on the input selected by 'input'
+pub fn run(
+    year: u16,
+    day: u8,
+    input: &InputArgs,
+    parts: Parts,
+) -> Result<Vec<Part>, Box<dyn Error>> {
+    let Some(entry) = registry::get(year, day) else {
+        if registry::entries(Some(year)).next().is_none() {
+            return Err(format!("Year {} is not implemented", year).into());
+        }
+        return Err(format!("Day {} of {} is not implemented", day, year).into());
+    };
+    let solution = entry.load(input)?;
+    solve(entry.files, solution.as_ref(), input, parts)
+}
+
+/// Parses the input of the day and times the parse step and the selected parts, a part that fails
+/// does not prevent the other one from being solved
+fn solve(
+    files: &DayFiles,
+    solution: &dyn AnySolution,
+    input: &InputArgs,
+    parts: Parts,
+) -> Result<Vec<Part>, Box<dyn Error>> {
+    let mut text = Vec::new();
+    input::select(files, input)?.read_to_end(&mut text)?;
+
+    // Malformed inputs are reported before solving, rather than as wrong answers or panics
+    let problems = info_span!("check", year = files.year, day = files.day)
+        .in_scope(|| solution.check(&mut Cursor::new(&text)));
+    if let Some(first) = problems.first() {
+        return Err(match problems.len() {
+            1 => first.to_string(),
+            n => format!(
+                "{} (and {} more problems, see `aoc check {} {}`)",
+                first,
+                n - 1,
+                files.year,
+                files.day
+            ),
+        }
+        .into());
+    }
+
+    let (parsed, parse_elapsed, parse_allocations) = timed(
+        info_span!("parse", year = files.year, day = files.day),
+        || solution.parse(&mut Cursor::new(&text)),
+    )?;
+
+    let solve_part = |part: u8, step: &dyn Fn() -> error::Result<String>| {
+        let (answer, solve, solve_allocations) = timed(
+            info_span!("part", year = files.year, day = files.day, part = part),
+            step,
+        )
+        .map_err(|e| e.to_string())?;
+        // The ledger holds the answers of the real input
+        if input.is_real() {
+            ledger::warn(files.name, part, &answer);
+        }
+        Ok(Record {
+            year: files.year,
+            day: files.day,
+            part,
+            answer,
+            parse: parse_elapsed,
+            solve,
+            parse_allocations,
+            solve_allocations,
+        })
+    };
+
+    let mut records = Vec::new();
+    if parts.one {
+        records.push((1, solve_part(1, &|| parsed.part_one())));
+    }
+    if parts.two {
+        records.push((2, solve_part(2, &|| parsed.part_two())));
+    }
+    Ok(records)
+}
+
+/// Times 'step' inside 'span', returning its result along with the elapsed time, and its heap usage
+/// with `--profile`
+fn timed<T>(
+    span: Span,
+    step: impl FnOnce() -> error::Result<T>,
+) -> error::Result<(T, Duration, Option<Allocations>)> {
+    let _span = span.entered();
+    let start = Instant::now();
+    let (value, allocations) = profile::measure(step);
+    let elapsed = start.elapsed();
+    Ok((value?, elapsed, allocations))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_answers_of_the_other_part() {
+        let input = InputArgs {
+            example: true,
+            ..Default::default()
+        };
+        let parts = run(2024, 14, &input, Parts::new(None)).unwrap();
+
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].0, 1);
+        assert_eq!(parts[0].1.as_ref().unwrap().answer, "12");
+        assert_eq!(parts[1].0, 2);
+        let Err(e) = &parts[1].1 else {
+            panic!("Part two of the example has no tree");
+        };
+        assert!(e.ends_with("the robots never form a tree"));
+    }
+}
@@ -0,0 +1,27 @@
+//! Animations of the days of 2024
+
+use std::error::Error;
+
+use common::input::{self, InputArgs};
+
+/// Plays the simulation of the input day step by step in the terminal, the warehouse is scaled up
+/// on part two of day fifteen
+pub fn animate(day: u8, input: &InputArgs, part: u8) -> Result<(), Box<dyn Error>> {
+    match day {
+        6 => {
+            let lab = y2024_day06::parse(input::select(&y2024_day06::FILES, input)?)?;
+            animation::terminal::play(y2024_day06::guard_walk(&lab))?;
+        }
+        14 => {
+            let config = y2024_day14::Config::load(input)?;
+            let robots = y2024_day14::parse(input::select(&y2024_day14::FILES, input)?)?;
+            animation::terminal::play(y2024_day14::robot_frames(&robots, &config))?;
+        }
+        15 => {
+            let warehouse = y2024_day15::parse(input::select(&y2024_day15::FILES, input)?)?;
+            animation::terminal::play(y2024_day15::pushes(&warehouse, part == 2)?)?;
+        }
+        _ => return Err(format!("Day {} of 2024 has no animation, try 6, 14 or 15", day).into()),
+    }
+    Ok(())
+}
@@ -0,0 +1,162 @@
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Environment variable holding the session token of the Advent of Code website
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable overriding [`DEFAULT_BASE_URL`]
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Website serving the puzzle inputs
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Outcome of fetching the input of a day
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already on disk, nothing was downloaded
+    Cached(PathBuf),
+    /// The input was downloaded and saved
+    Downloaded(PathBuf),
+}
+
+/// Downloads the input of 'day' of 'year' from 'base_url' into 'path', unless 'path' already exists.
+///
+/// The session token is only needed when the input has to be downloaded.
+pub fn fetch(
+    base_url: &str,
+    session: Option<&str>,
+    year: u16,
+    day: u8,
+    path: &Path,
+) -> Result<Fetched, Box<dyn Error>> {
+    if path.exists() {
+        return Ok(Fetched::Cached(path.to_path_buf()));
+    }
+
+    let session = session
+        .filter(|session| !session.is_empty())
+        .ok_or_else(|| format!("Set {} to download the input of day {}", SESSION_VAR, day))?;
+
+    let url = format!(
+        "{}/{}/day/{}/input",
+        base_url.trim_end_matches('/'),
+        year,
+        day
+    );
+    let input = ureq::get(&url)
+        .header("Cookie", &format!("session={}", session))
+        .header("User-Agent", "advent_of_code input fetcher")
+        .call()
+        .map_err(|e| format!("Cannot download '{}': {}", url, e))?
+        .body_mut()
+        .read_to_string()
+        .map_err(|e| format!("Cannot read '{}': {}", url, e))?;
+
+    // Write to a temporary file first, so that a failed write never leaves a partial input in the cache
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let partial = path.with_extension("part");
+    fs::write(&partial, input)?;
+    fs::rename(&partial, path)?;
+
+    Ok(Fetched::Downloaded(path.to_path_buf()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+
+    /// Serves 'status' and 'body' to every request on a local port, sending back the request lines and headers
+    fn stub_server(status: &'static str, body: &'static str) -> (String, Receiver<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let request: Vec<String> = BufReader::new(&stream)
+                    .lines()
+                    .map(|line| line.unwrap())
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                if sender.send(request).is_err() {
+                    break;
+                }
+            }
+        });
+
+        (url, receiver)
+    }
+
+    /// Empty directory unique to the calling test
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_missing_input() {
+        let (url, requests) = stub_server("200 OK", "1 2 3\n");
+        let path = test_dir("download").join("y2024_day07").join("input.txt");
+
+        let fetched = fetch(&url, Some("secret"), 2024, 7, &path).unwrap();
+
+        assert_eq!(fetched, Fetched::Downloaded(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+        let request = requests.recv().unwrap();
+        assert_eq!(request[0], "GET /2024/day/7/input HTTP/1.1");
+        assert!(request
+            .iter()
+            .any(|header| header.eq_ignore_ascii_case("cookie: session=secret")));
+    }
+
+    #[test]
+    fn never_downloads_cached_input() {
+        let (url, requests) = stub_server("200 OK", "new\n");
+        let path = test_dir("cached").join("input.txt");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "old\n").unwrap();
+
+        let fetched = fetch(&url, None, 2024, 3, &path).unwrap();
+
+        assert_eq!(fetched, Fetched::Cached(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "old\n");
+        assert!(requests.try_recv().is_err());
+    }
+
+    #[test]
+    fn keeps_nothing_on_http_error() {
+        let (url, _requests) = stub_server("400 Bad Request", "Puzzle inputs differ by user.");
+        let path = test_dir("error").join("input.txt");
+
+        let error = fetch(&url, Some("expired"), 2024, 1, &path).unwrap_err();
+
+        assert!(error.to_string().contains("400"), "{}", error);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn requires_session_to_download() {
+        let path = test_dir("session").join("input.txt");
+
+        let error = fetch("http://127.0.0.1:9", None, 2024, 1, &path).unwrap_err();
+
+        assert!(error.to_string().contains(SESSION_VAR), "{}", error);
+    }
+}
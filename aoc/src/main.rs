@@ -0,0 +1,559 @@
+/*
+    Runs the solutions of every day from a single binary
+*/
+mod days;
+mod fetch;
+mod profile;
+mod scaffold;
+mod watch;
+
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand};
+use common::input::{self, InputArgs, Source};
+use common::ledger::{self, Ledger, Verdict};
+use common::lint;
+use common::output::{self, Format};
+
+use days::{Parts, Results};
+use fetch::Fetched;
+
+#[global_allocator]
+static ALLOCATOR: profile::Counting = profile::Counting;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Selects the puzzle parameters, see `aoc.toml`
+#[derive(Args)]
+struct ConfigArgs {
+    /// Read the puzzle parameters from PATH [default: $AOC_CONFIG, or aoc.toml at the workspace root]
+    #[arg(long, value_name = "PATH")]
+    config: Option<String>,
+
+    /// Override a puzzle parameter of the config file, e.g. `--set y2024_day14.width=11`, can be
+    /// repeated
+    #[arg(long, value_name = "KEY=VALUE")]
+    set: Vec<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs the solution of a single day, or of every day with `--all`
+    Run {
+        /// Year of the day, every year is run by `--all` without it
+        #[arg(required_unless_present = "all")]
+        year: Option<u16>,
+
+        /// Day to run
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        day: Option<u8>,
+
+        /// Only run the given part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Run every implemented day of the year, or of every year
+        #[arg(long)]
+        all: bool,
+
+        /// Read the puzzle input from PATH, or from stdin if PATH is -
+        #[arg(long, value_name = "PATH", conflicts_with_all = ["all", "example"])]
+        input: Option<String>,
+
+        /// Use the bundled example inputs
+        #[arg(long)]
+        example: bool,
+
+        /// Print the answers as a table (text) or as one JSON record per line (json)
+        #[arg(long, default_value = "text")]
+        format: Format,
+
+        /// Count the allocations, bytes allocated and peak memory of the parse step and of each
+        /// part, running the days one after the other
+        #[arg(long)]
+        profile: bool,
+
+        #[command(flatten)]
+        config: ConfigArgs,
+    },
+
+    /// Downloads the real input of a day, unless it is already on disk.
+    ///
+    /// The session token is read from the AOC_SESSION environment variable. The input is saved where
+    /// `run` looks for it: inside $AOC_INPUT_DIR if set, next to the day's sources otherwise.
+    Fetch {
+        /// Year of the day
+        year: u16,
+
+        /// Day to download
+        day: u8,
+
+        /// Website serving the inputs
+        #[arg(long, env = fetch::BASE_URL_VAR, default_value = fetch::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+
+    /// Checks that an input follows the format expected by the solution of a day, listing every
+    /// problem found with its line and column
+    Check {
+        /// Year of the day
+        year: u16,
+
+        /// Day of the input
+        day: u8,
+
+        /// Input to check, - for stdin [default: the real input of the day]
+        file: Option<String>,
+
+        #[command(flatten)]
+        config: ConfigArgs,
+    },
+
+    /// Plays the simulation of day 6, 14 or 15 in the terminal, with play/pause (space), step
+    /// (right), back (left), speed (+/-) and quit (q) controls
+    Animate {
+        /// Year of the day
+        year: u16,
+
+        /// Day to animate
+        day: u8,
+
+        /// Part of the simulation, only day 15 differs between its parts
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Read the puzzle input from PATH, or from stdin if PATH is -
+        #[arg(long, value_name = "PATH", conflicts_with = "example")]
+        input: Option<String>,
+
+        /// Use the bundled example input
+        #[arg(long)]
+        example: bool,
+
+        #[command(flatten)]
+        config: ConfigArgs,
+    },
+
+    /// Rebuilds and runs a day every time its sources, its input or the config file change, showing
+    /// how the answers differ from the previous run
+    Watch {
+        /// Year of the day
+        year: u16,
+
+        /// Day to watch
+        day: u8,
+
+        /// Read the puzzle input from PATH
+        #[arg(long, value_name = "PATH", conflicts_with = "example")]
+        input: Option<String>,
+
+        /// Use the bundled example input
+        #[arg(long)]
+        example: bool,
+
+        /// Time after which a run of the day is stopped, in seconds
+        #[arg(long, value_name = "SECONDS", default_value_t = 60.0)]
+        timeout: f64,
+
+        #[command(flatten)]
+        config: ConfigArgs,
+    },
+
+    /// Records what the website answered to an answer in the ledger, so that the solutions warn
+    /// when they give a rejected answer again
+    Record {
+        /// Year of the day
+        year: u16,
+
+        /// Day of the answer
+        day: u8,
+
+        /// Part of the answer
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Submitted answer
+        answer: String,
+
+        /// What the website answered: correct, too-high, too-low or wrong
+        verdict: Verdict,
+    },
+
+    /// Creates the crate of a new day from the template and adds it to the workspace
+    New {
+        /// Year of the day
+        #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+        year: u16,
+
+        /// Day to create
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+/// Prints one row per selected part with its answer or error, or the error returned by the day
+fn print_table(results: &Results) {
+    let answer_width = results
+        .iter()
+        .flat_map(|(_, result)| match result {
+            Ok(parts) => parts
+                .iter()
+                .map(|(_, part)| match part {
+                    Ok(record) => record.answer.len(),
+                    Err(e) => e.len(),
+                })
+                .collect(),
+            Err(e) => vec![e.len()],
+        })
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    println!(
+        "{:>4} | {:>3} | {:>4} | {:<answer_width$} | {:>10}",
+        "Year", "Day", "Part", "Answer", "Time"
+    );
+    println!(
+        "{:-<4}-+-{:-<3}-+-{:-<4}-+-{:-<answer_width$}-+-{:-<10}",
+        "", "", "", "", ""
+    );
+
+    for ((year, day), result) in results {
+        match result {
+            Ok(parts) => {
+                for (part, result) in parts {
+                    match result {
+                        Ok(record) => println!(
+                            "{:>4} | {:>3} | {:>4} | {:<answer_width$} | {:>10}",
+                            year,
+                            day,
+                            part,
+                            record.answer,
+                            format_duration(record.solve)
+                        ),
+                        Err(e) => println!(
+                            "{:>4} | {:>3} | {:>4} | {:<answer_width$} | {:>10}",
+                            year, day, part, e, "-"
+                        ),
+                    }
+                }
+            }
+            Err(e) => println!(
+                "{:>4} | {:>3} | {:>4} | {:<answer_width$} | {:>10}",
+                year, day, "-", e, "-"
+            ),
+        }
+    }
+}
+
+/// Prints the time and heap usage of the parse step and of each part of the profiled days
+fn print_profile(results: &Results) {
+    println!(
+        "{:>4} | {:>3} | {:<8} | {:>10} | {:>11} | {:>10} | {:>10}",
+        "Year", "Day", "Step", "Time", "Allocations", "Allocated", "Peak"
+    );
+    println!(
+        "{:-<4}-+-{:-<3}-+-{:-<8}-+-{:-<10}-+-{:-<11}-+-{:-<10}-+-{:-<10}",
+        "", "", "", "", "", "", ""
+    );
+
+    for ((year, day), result) in results {
+        let Ok(parts) = result else {
+            continue;
+        };
+        // Every record holds the same parse step, the failed parts have no record
+        let records: Vec<_> = parts
+            .iter()
+            .filter_map(|(_, part)| part.as_ref().ok())
+            .collect();
+        let parse = records
+            .first()
+            .map(|record| ("parse".to_string(), record.parse, record.parse_allocations));
+        let parts = records.iter().map(|record| {
+            (
+                format!("part {}", record.part),
+                record.solve,
+                record.solve_allocations,
+            )
+        });
+        for (step, time, allocations) in parse.into_iter().chain(parts) {
+            let allocations = allocations.unwrap_or_default();
+            println!(
+                "{:>4} | {:>3} | {:<8} | {:>10} | {:>11} | {:>10} | {:>10}",
+                year,
+                day,
+                step,
+                format_duration(time),
+                allocations.count,
+                profile::format_bytes(allocations.bytes),
+                profile::format_bytes(allocations.peak)
+            );
+        }
+    }
+}
+
+/// Prints one JSON record per solved part, and the errors to stderr
+fn print_json(results: &Results) {
+    for ((year, day), result) in results {
+        match result {
+            Ok(parts) => {
+                for (part, result) in parts {
+                    match result {
+                        Ok(record) => println!("{}", record.to_json()),
+                        Err(e) => eprintln!("Error: {} day {} part {}: {}", year, day, part, e),
+                    }
+                }
+            }
+            Err(e) => eprintln!("Error: {} day {}: {}", year, day, e),
+        }
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3} ms", output::as_ms(duration))
+}
+
+fn main() -> ExitCode {
+    common::trace::init();
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            all,
+            input,
+            example,
+            format,
+            profile,
+            config,
+        } => {
+            let puzzles = match (year, day) {
+                (year, _) if all => days::puzzles(year),
+                (Some(year), Some(day)) => vec![(year, day)],
+                _ => unreachable!("The year and the day are required without --all"),
+            };
+            if puzzles.is_empty() {
+                eprintln!(
+                    "Error: No day of {} is implemented",
+                    year.unwrap_or_default()
+                );
+                return ExitCode::FAILURE;
+            }
+            let parts = Parts::new(part);
+            let input = InputArgs {
+                input,
+                example,
+                config: config.config,
+                set: config.set,
+                ..Default::default()
+            };
+
+            // The allocation counters are shared, so the profiled days must not overlap
+            let results = if profile {
+                profile::enable();
+                days::run_each(puzzles, &input, parts)
+            } else {
+                days::run_all(puzzles, &input, parts)
+            };
+
+            match format {
+                Format::Text => {
+                    print_table(&results);
+                    if profile {
+                        println!();
+                        print_profile(&results);
+                    }
+                }
+                Format::Json => print_json(&results),
+            }
+
+            let failed = |result: &Result<Vec<days::Part>, String>| match result {
+                Ok(parts) => parts.iter().any(|(_, part)| part.is_err()),
+                Err(_) => true,
+            };
+            if results.iter().any(|(_, result)| failed(result)) {
+                return ExitCode::FAILURE;
+            }
+        }
+
+        Command::Fetch {
+            year,
+            day,
+            base_url,
+        } => {
+            let Some(files) = days::files(year, day) else {
+                eprintln!("Error: Day {} of {} is not implemented", day, year);
+                return ExitCode::FAILURE;
+            };
+            let Source::Path(path) = input::resolve(files, &InputArgs::default()) else {
+                unreachable!("The real input is always a file");
+            };
+
+            let session = std::env::var(fetch::SESSION_VAR).ok();
+            match fetch::fetch(&base_url, session.as_deref(), year, day, &path) {
+                Ok(Fetched::Cached(path)) => {
+                    println!(
+                        "Day {} of {} is already cached in {}",
+                        day,
+                        year,
+                        path.display()
+                    )
+                }
+                Ok(Fetched::Downloaded(path)) => {
+                    println!("Downloaded day {} of {} to {}", day, year, path.display())
+                }
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+
+        Command::Check {
+            year,
+            day,
+            file,
+            config,
+        } => {
+            let Some(files) = days::files(year, day) else {
+                eprintln!("Error: Day {} of {} is not implemented", day, year);
+                return ExitCode::FAILURE;
+            };
+            let args = InputArgs {
+                input: file,
+                config: config.config,
+                set: config.set,
+                ..Default::default()
+            };
+            let source = input::resolve(files, &args);
+            let reader = match input::open(&source) {
+                Ok(reader) => reader,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let problems = days::check(year, day, reader, &args).unwrap_or_default();
+            let name = match &source {
+                Source::Path(path) => path.display().to_string(),
+                Source::Stdin => "stdin".to_string(),
+            };
+            lint::report(&problems, name);
+            if !problems.is_empty() {
+                return ExitCode::FAILURE;
+            }
+        }
+
+        Command::Animate {
+            year,
+            day,
+            part,
+            input,
+            example,
+            config,
+        } => {
+            let input = InputArgs {
+                input,
+                example,
+                config: config.config,
+                set: config.set,
+                ..Default::default()
+            };
+            if let Err(e) = days::animate(year, day, &input, part) {
+                eprintln!("Error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+
+        Command::Watch {
+            year,
+            day,
+            input,
+            example,
+            timeout,
+            config,
+        } => {
+            let Some(files) = days::files(year, day) else {
+                eprintln!("Error: Day {} of {} is not implemented", day, year);
+                return ExitCode::FAILURE;
+            };
+            let timeout = match Duration::try_from_secs_f64(timeout) {
+                Ok(timeout) => timeout,
+                Err(e) => {
+                    eprintln!("Error: Invalid --timeout: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let input = InputArgs {
+                input,
+                example,
+                config: config.config,
+                set: config.set,
+                ..Default::default()
+            };
+            if let Err(e) = watch::watch(files, &input, timeout) {
+                eprintln!("Error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+
+        Command::Record {
+            year,
+            day,
+            part,
+            answer,
+            verdict,
+        } => {
+            let Some(files) = days::files(year, day) else {
+                eprintln!("Error: Day {} of {} is not implemented", day, year);
+                return ExitCode::FAILURE;
+            };
+            let path = ledger::path();
+            let recorded = Ledger::load(&path).and_then(|mut ledger| {
+                ledger.record(files.name, part, &answer, verdict)?;
+                ledger.save(&path)
+            });
+            match recorded {
+                Ok(()) => println!(
+                    "Recorded {} for day {} of {} part {} in {}",
+                    answer.trim(),
+                    day,
+                    year,
+                    part,
+                    path.display()
+                ),
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+
+        Command::New { year, day } => {
+            match scaffold::generate(scaffold::workspace_dir(), year, day) {
+                Ok(dir) => {
+                    println!("Created {}", dir.display());
+                    println!(
+                        "Add it to registry/Cargo.toml and to the days of {} in registry/src/lib.rs to run it with the runner",
+                        year
+                    );
+                }
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,136 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+
+use common::output::Allocations;
+
+/// Allocator of the runner, counting the allocations of every thread once [`enable`] is called.
+///
+/// The counters are shared by the whole process, so the steps of a profiled run must not overlap.
+pub struct Counting;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static COUNT: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+// Signed, as memory allocated before profiling started can be freed after
+static CURRENT: AtomicI64 = AtomicI64::new(0);
+static PEAK: AtomicI64 = AtomicI64::new(0);
+
+/// Starts counting the allocations, which costs a few atomic operations per allocation
+pub fn enable() {
+    ENABLED.store(true, Ordering::SeqCst);
+}
+
+/// Runs 'step', returning its heap usage along with its result if profiling is enabled
+pub fn measure<T>(step: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return (step(), None);
+    }
+    let count = COUNT.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let start = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(start, Ordering::Relaxed);
+
+    let value = step();
+
+    let allocations = Allocations {
+        count: COUNT.load(Ordering::Relaxed) - count,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: (PEAK.load(Ordering::Relaxed) - start).max(0) as u64,
+    };
+    (value, Some(allocations))
+}
+
+/// Counts an allocation of 'size' bytes, or a reallocation growing a block by 'growth' bytes
+fn allocated(size: usize, growth: i64) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    COUNT.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size as u64, Ordering::Relaxed);
+    let current = CURRENT.fetch_add(growth, Ordering::Relaxed) + growth;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+fn freed(size: usize) {
+    if ENABLED.load(Ordering::Relaxed) {
+        CURRENT.fetch_sub(size as i64, Ordering::Relaxed);
+    }
+}
+
+// SAFETY: every call is forwarded to the system allocator, the counters do not allocate
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size(), layout.size() as i64);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size(), layout.size() as i64);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        freed(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            allocated(new_size, new_size as i64 - layout.size() as i64);
+        }
+        new_ptr
+    }
+}
+
+/// Size in bytes with the largest binary unit keeping it at 1 or more, e.g. `1.5 KiB`
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The runner's allocator is also the one of its tests, which run concurrently, hence the lower
+    // bounds
+    #[test]
+    fn counts_the_allocations_of_a_step() {
+        enable();
+        let (total, allocations) = measure(|| {
+            let kept: Vec<u64> = (0..1000).collect();
+            let dropped = vec![0u8; 1 << 16];
+            kept.iter().sum::<u64>() + dropped.len() as u64
+        });
+        let allocations = allocations.unwrap();
+        assert_eq!(total, 499_500 + (1 << 16));
+        assert!(allocations.count >= 2);
+        assert!(allocations.bytes >= 8000 + (1 << 16));
+        assert!(allocations.peak >= 1 << 16);
+    }
+
+    #[test]
+    fn formats_the_sizes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(5 << 20), "5.0 MiB");
+        assert_eq!(format_bytes(3 << 40), "3.0 TiB");
+    }
+}
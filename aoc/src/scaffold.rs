@@ -0,0 +1,194 @@
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Root of the workspace the runner was built from
+pub fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("The runner lives inside the workspace")
+}
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+
+/// Name of the crate of a day, e.g. `y2024_day07`
+pub fn crate_name(year: u16, day: u8) -> String {
+    format!("y{}_day{:02}", year, day)
+}
+
+/// Directory of the crate of a day relative to the workspace, e.g. `y2024/day07`
+pub fn crate_dir(year: u16, day: u8) -> PathBuf {
+    Path::new(&format!("y{}", year)).join(format!("day{:02}", day))
+}
+
+/// Creates the crate of 'day' of 'year' inside 'workspace', adding the directory of the year to the
+/// members of the workspace if it is the first day of the year.
+///
+/// Returns the directory of the new crate. Fails without touching anything if the crate already exists.
+pub fn generate(workspace: &Path, year: u16, day: u8) -> Result<PathBuf, Box<dyn Error>> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day {} is not between 1 and 25", day).into());
+    }
+    let name = crate_name(year, day);
+    let dir = workspace.join(crate_dir(year, day));
+    if dir.exists() {
+        return Err(format!("'{}' already exists", dir.display()).into());
+    }
+
+    let manifest_path = workspace.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)
+        .map_err(|e| format!("Cannot read '{}': {}", manifest_path.display(), e))?;
+    let manifest = add_member(&manifest, &format!("y{}/*", year))?;
+
+    let render = |template: &str| {
+        template
+            .replace("{{name}}", &name)
+            .replace("{{year}}", &year.to_string())
+            .replace("{{day}}", &day.to_string())
+    };
+    let src = dir.join("src");
+    fs::create_dir_all(&src)?;
+    fs::write(dir.join("Cargo.toml"), render(CARGO_TEMPLATE))?;
+    fs::write(src.join("main.rs"), render(MAIN_TEMPLATE))?;
+    fs::write(src.join("lib.rs"), render(LIB_TEMPLATE))?;
+    fs::write(src.join("test.txt"), "")?;
+    fs::write(manifest_path, manifest)?;
+
+    Ok(dir)
+}
+
+/// Adds 'name' to the `members` array of a workspace manifest, right after the last year directory,
+/// unless it is already a member.
+///
+/// Only the new entry is written, the layout of the existing entries is kept as is.
+fn add_member(manifest: &str, name: &str) -> Result<String, String> {
+    let malformed = || "Cannot find the workspace members in Cargo.toml".to_string();
+    let key = manifest
+        .lines()
+        .scan(0, |offset, line| {
+            let start = *offset;
+            *offset += line.len() + 1;
+            Some((start, line))
+        })
+        .find(|(_, line)| line.trim_start().starts_with("members"))
+        .map(|(start, _)| start)
+        .ok_or_else(malformed)?;
+    let open = key + manifest[key..].find('[').ok_or_else(malformed)?;
+    let close = open + manifest[open..].find(']').ok_or_else(malformed)?;
+
+    // Quoted entries of the array, with the offset just past their closing quote
+    let mut entries = Vec::new();
+    let mut rest = &manifest[open + 1..close];
+    while let Some(start) = rest.find('"') {
+        let end = rest[start + 1..].find('"').ok_or_else(malformed)? + start + 1;
+        let offset = close - rest.len() + end + 1;
+        entries.push((&rest[start + 1..end], offset));
+        rest = &rest[end + 1..];
+    }
+
+    if entries.iter().any(|(entry, _)| *entry == name) {
+        return Ok(manifest.to_string());
+    }
+
+    let mut updated = manifest.to_string();
+    match entries.iter().rev().find(|(entry, _)| is_year_dir(entry)) {
+        Some(&(_, offset)) => updated.insert_str(offset, &format!(", \"{}\"", name)),
+        None if entries.is_empty() => updated.insert_str(close, &format!("\"{}\"", name)),
+        None => updated.insert_str(close, &format!(", \"{}\"", name)),
+    }
+    Ok(updated)
+}
+
+/// Whether a workspace member is the directory of a year, e.g. `y2024/*`
+fn is_year_dir(member: &str) -> bool {
+    member
+        .strip_prefix('y')
+        .and_then(|member| member.strip_suffix("/*"))
+        .is_some_and(|year| !year.is_empty() && year.bytes().all(|b| b.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_every_day() {
+        assert_eq!(crate_name(2024, 7), "y2024_day07");
+        assert_eq!(crate_name(2015, 25), "y2015_day25");
+        assert_eq!(crate_dir(2024, 7), Path::new("y2024").join("day07"));
+    }
+
+    #[test]
+    fn keeps_the_layout_of_the_members() {
+        let manifest = "[workspace]\nresolver = \"2\"\nmembers = [\n    \"aoc\",\n    \"y2023/*\", \n    \"y2024/*\"\n, \"common\"]\n";
+
+        let updated = add_member(manifest, "y2025/*").unwrap();
+
+        assert_eq!(
+            updated,
+            "[workspace]\nresolver = \"2\"\nmembers = [\n    \"aoc\",\n    \"y2023/*\", \n    \"y2024/*\", \"y2025/*\"\n, \"common\"]\n"
+        );
+    }
+
+    #[test]
+    fn adds_the_first_member() {
+        assert_eq!(
+            add_member("[workspace]\nmembers = []\n", "y2024/*").unwrap(),
+            "[workspace]\nmembers = [\"y2024/*\"]\n"
+        );
+        assert_eq!(
+            add_member("[workspace]\nmembers = [\"aoc\"]\n", "y2024/*").unwrap(),
+            "[workspace]\nmembers = [\"aoc\", \"y2024/*\"]\n"
+        );
+    }
+
+    #[test]
+    fn keeps_existing_members() {
+        let manifest = "[workspace]\nmembers = [\"y2024/*\"]\n";
+        assert_eq!(add_member(manifest, "y2024/*").unwrap(), manifest);
+        assert!(!is_year_dir("aoc"));
+        assert!(!is_year_dir("y/*"));
+    }
+
+    #[test]
+    fn generates_a_day_crate() {
+        let workspace = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&workspace);
+        fs::create_dir_all(&workspace).unwrap();
+        fs::write(
+            workspace.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"aoc\", \"y2024/*\"]\n",
+        )
+        .unwrap();
+
+        let dir = generate(&workspace, 2025, 2).unwrap();
+
+        assert_eq!(dir, workspace.join("y2025").join("day02"));
+        assert_eq!(
+            fs::read_to_string(workspace.join("Cargo.toml")).unwrap(),
+            "[workspace]\nmembers = [\"aoc\", \"y2024/*\", \"y2025/*\"]\n"
+        );
+        assert!(fs::read_to_string(dir.join("Cargo.toml"))
+            .unwrap()
+            .contains("name = \"y2025_day02\""));
+        let lib = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("day_files!(2025, 2, \"test.txt\")"));
+        assert!(lib.contains("adventofcode.com/2025/day/2"));
+        assert!(fs::read_to_string(dir.join("src/main.rs"))
+            .unwrap()
+            .contains("use y2025_day02::"));
+        assert_eq!(fs::read_to_string(dir.join("src/test.txt")).unwrap(), "");
+        assert!(generate(&workspace, 2025, 2).is_err());
+        assert!(generate(&workspace, 2025, 26).is_err());
+
+        // The other days of the year are already covered by its directory
+        generate(&workspace, 2025, 3).unwrap();
+        assert_eq!(
+            fs::read_to_string(workspace.join("Cargo.toml")).unwrap(),
+            "[workspace]\nmembers = [\"aoc\", \"y2024/*\", \"y2025/*\"]\n"
+        );
+        fs::remove_dir_all(&workspace).unwrap();
+    }
+}
@@ -0,0 +1,484 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::{self, IsTerminal, Read};
+use std::path::{self as fs_path, Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use common::config;
+use common::input::{self, DayFiles, InputArgs, Source};
+use common::ledger::{self, Ledger};
+use notify::{EventKind, RecursiveMode, Watcher};
+
+use crate::scaffold;
+
+/// Time waited for the other events of a single save, e.g. editors writing a file in several steps
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Time between two checks of whether the day is still running
+const POLL: Duration = Duration::from_millis(10);
+
+/// Answers of a run of a day, by part
+pub type Answers = BTreeMap<u8, String>;
+
+/// Difference between the answer of a part in two runs
+#[derive(Debug, PartialEq, Eq)]
+pub enum Change<'a> {
+    New {
+        part: u8,
+        answer: &'a str,
+    },
+    Same {
+        part: u8,
+        answer: &'a str,
+    },
+    Changed {
+        part: u8,
+        before: &'a str,
+        after: &'a str,
+    },
+    Gone {
+        part: u8,
+        before: &'a str,
+    },
+}
+
+/// Compares the answers of every part found in either run
+pub fn diff<'a>(previous: &'a Answers, current: &'a Answers) -> Vec<Change<'a>> {
+    let parts: BTreeSet<u8> = previous.keys().chain(current.keys()).copied().collect();
+    parts
+        .into_iter()
+        .map(|part| {
+            match (
+                previous.get(&part).map(String::as_str),
+                current.get(&part).map(String::as_str),
+            ) {
+                (None, Some(answer)) => Change::New { part, answer },
+                (Some(before), Some(after)) if before == after => Change::Same {
+                    part,
+                    answer: after,
+                },
+                (Some(before), Some(after)) => Change::Changed {
+                    part,
+                    before,
+                    after,
+                },
+                (Some(before), None) => Change::Gone { part, before },
+                (None, None) => unreachable!("Every part comes from one of the runs"),
+            }
+        })
+        .collect()
+}
+
+/// Line describing 'change', changed and lost answers are highlighted with 'color'
+fn describe(change: &Change, color: bool) -> String {
+    let highlight = |text: String| {
+        if color {
+            format!("\x1b[1;33m{}\x1b[0m", text)
+        } else {
+            text
+        }
+    };
+    match change {
+        Change::New { part, answer } => format!("Part {}: {}", part, answer),
+        Change::Same { part, answer } => format!("Part {}: {} (unchanged)", part, answer),
+        Change::Changed {
+            part,
+            before,
+            after,
+        } => highlight(format!("Part {}: {} -> {} (changed)", part, before, after)),
+        Change::Gone { part, before } => {
+            highlight(format!("Part {}: {} -> no answer", part, before))
+        }
+    }
+}
+
+/// Rebuilds and runs the binary of the day on the input selected by 'args' every time its sources,
+/// its input or the config file change, printing how the answers differ from the previous run.
+/// A run still going after 'timeout' is stopped, and waits for the next change like a failed one.
+///
+/// Only returns on errors, the user stops it with Ctrl-C.
+pub fn watch(files: &DayFiles, args: &InputArgs, timeout: Duration) -> Result<(), Box<dyn Error>> {
+    let Source::Path(input_path) = input::resolve(files, args) else {
+        return Err("Cannot watch stdin, give the input with --input <PATH>".into());
+    };
+    let config_path = args
+        .config
+        .clone()
+        .or_else(|| env::var(config::CONFIG_VAR).ok())
+        .unwrap_or_else(|| config::DEFAULT_PATH.to_string());
+
+    // Files are watched through their directory, as editors often replace them rather than write them
+    let src = fs::canonicalize(Path::new(files.manifest_dir).join("src"))?;
+    let watched_files: Vec<PathBuf> = [input_path, PathBuf::from(config_path)]
+        .iter()
+        .filter_map(|path| canonical_file(path))
+        .collect();
+
+    let (sender, events) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+    watcher.watch(&src, RecursiveMode::Recursive)?;
+    let dirs: BTreeSet<&Path> = watched_files
+        .iter()
+        .filter_map(|file| file.parent())
+        .filter(|dir| !dir.starts_with(&src))
+        .collect();
+    for dir in dirs {
+        watcher.watch(dir, RecursiveMode::NonRecursive)?;
+    }
+    let is_watched =
+        |path: &Path| path.starts_with(&src) || watched_files.iter().any(|file| file == path);
+
+    let color = io::stdout().is_terminal();
+    let mut previous = Answers::new();
+    println!(
+        "Watching {} and its input, press Ctrl-C to stop",
+        src.display()
+    );
+    loop {
+        println!("Running {}...", files.name);
+        match run_day(files.name, args, timeout) {
+            Ok(answers) => {
+                for change in diff(&previous, &answers) {
+                    println!("{}", describe(&change, color));
+                }
+                // The ledger is read again on every run, as it may be recorded to while watching
+                if args.is_real() {
+                    match Ledger::load(&ledger::path()) {
+                        Ok(ledger) => {
+                            for (part, answer) in &answers {
+                                ledger.warn(files.name, *part, answer);
+                            }
+                        }
+                        Err(e) => eprintln!("Warning: {}", e),
+                    }
+                }
+                previous = answers;
+            }
+            // The answers of the last successful run stay the reference
+            Err(e) => eprintln!("{}", e),
+        }
+
+        let changed = next_change(&events, is_watched)?;
+        println!("\n{} changed", changed.display());
+    }
+}
+
+/// Path of 'path' with its directory resolved, if the directory exists
+fn canonical_file(path: &Path) -> Option<PathBuf> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    Some(fs::canonicalize(dir).ok()?.join(path.file_name()?))
+}
+
+/// Waits for a change of a watched path, then for the end of the burst of events it belongs to
+fn next_change(
+    events: &Receiver<notify::Result<notify::Event>>,
+    is_watched: impl Fn(&Path) -> bool,
+) -> Result<PathBuf, Box<dyn Error>> {
+    let changed_path = |event: notify::Result<notify::Event>| -> Option<PathBuf> {
+        let event = event.ok()?;
+        if matches!(event.kind, EventKind::Access(_)) {
+            return None;
+        }
+        event.paths.into_iter().find(|path| is_watched(path))
+    };
+
+    let changed = loop {
+        if let Some(path) = changed_path(events.recv()?) {
+            break path;
+        }
+    };
+    loop {
+        match events.recv_timeout(DEBOUNCE) {
+            Ok(_) => continue,
+            Err(RecvTimeoutError::Timeout) => return Ok(changed),
+            Err(RecvTimeoutError::Disconnected) => return Err("The file watcher stopped".into()),
+        }
+    }
+}
+
+/// Builds and runs the binary of the day, returning its answers, or the errors of cargo or of the day
+fn run_day(name: &str, args: &InputArgs, timeout: Duration) -> Result<Answers, String> {
+    let executable = build_day(name)?;
+
+    // The binary is run directly, as killing `cargo run` would leave the day running
+    let mut command = Command::new(executable);
+    command.args(["--format", "json"]).args(day_args(args));
+    let output = run_with_timeout(command, timeout)
+        .map_err(|e| format!("Cannot run {}: {}", name, e))?
+        .ok_or_else(|| format!("{} did not finish within {:?}", name, timeout))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr)
+            .trim_end()
+            .to_string());
+    }
+    parse_answers(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Builds the binary of the day with cargo, returning its path
+fn build_day(name: &str) -> Result<PathBuf, String> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = Command::new(cargo);
+    command.current_dir(scaffold::workspace_dir()).args([
+        "build",
+        "--release",
+        "--quiet",
+        "--package",
+        name,
+        "--message-format=json-render-diagnostics",
+    ]);
+    if io::stderr().is_terminal() {
+        command.arg("--color=always");
+    }
+
+    let output = command
+        .output()
+        .map_err(|e| format!("Cannot run cargo: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr)
+            .trim_end()
+            .to_string());
+    }
+    executable(&String::from_utf8_lossy(&output.stdout))
+        .ok_or_else(|| format!("Cargo built no binary for {}", name))
+}
+
+/// Path of the last binary in the JSON messages printed by `cargo build`
+fn executable(stdout: &str) -> Option<PathBuf> {
+    stdout.lines().rev().find_map(|line| {
+        let message: serde_json::Value = serde_json::from_str(line).ok()?;
+        message["executable"].as_str().map(PathBuf::from)
+    })
+}
+
+/// Runs 'command', killing it after 'timeout', in which case there is no output
+fn run_with_timeout(mut command: Command, timeout: Duration) -> io::Result<Option<Output>> {
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // The pipes are served by threads, so that a large output never blocks the child
+    let stdout = read_in_background(child.stdout.take().expect("stdout is piped"));
+    let stderr = read_in_background(child.stderr.take().expect("stderr is piped"));
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(POLL);
+    };
+
+    Ok(Some(Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    }))
+}
+
+fn read_in_background(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        let _ = pipe.read_to_end(&mut bytes);
+        bytes
+    })
+}
+
+/// Options of the day binaries selecting the same input and parameters as 'args', with the paths
+/// made absolute, as they are relative to the working directory of the watcher
+fn day_args(args: &InputArgs) -> Vec<String> {
+    let mut day_args = Vec::new();
+    if let Some(input) = &args.input {
+        day_args.extend(["--input".to_string(), absolute(input)]);
+    }
+    if args.example {
+        day_args.push("--example".to_string());
+    }
+    let config = args
+        .config
+        .clone()
+        .or_else(|| env::var(config::CONFIG_VAR).ok());
+    if let Some(config) = config {
+        day_args.extend(["--config".to_string(), absolute(&config)]);
+    }
+    for set in &args.set {
+        day_args.extend(["--set".to_string(), set.clone()]);
+    }
+    day_args
+}
+
+/// 'path' resolved from the working directory, as the watcher resolves the files it watches
+fn absolute(path: &str) -> String {
+    canonical_file(Path::new(path))
+        .or_else(|| fs_path::absolute(path).ok())
+        .map_or_else(|| path.to_string(), |path| path.display().to_string())
+}
+
+/// Answers of the JSON records printed by a day binary with `--format json`
+fn parse_answers(stdout: &str) -> Result<Answers, String> {
+    stdout
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let record: serde_json::Value = serde_json::from_str(line)
+                .map_err(|e| format!("Unexpected output '{}': {}", line, e))?;
+            match (record["part"].as_u64(), record["answer"].as_str()) {
+                (Some(part), Some(answer)) => Ok((part as u8, answer.to_string())),
+                _ => Err(format!("Unexpected output '{}'", line)),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers(parts: &[(u8, &str)]) -> Answers {
+        parts
+            .iter()
+            .map(|&(part, answer)| (part, answer.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn diffs_every_part() {
+        let previous = answers(&[(1, "41"), (2, "6")]);
+        let current = answers(&[(1, "41"), (2, "7")]);
+        assert_eq!(
+            diff(&previous, &current),
+            [
+                Change::Same {
+                    part: 1,
+                    answer: "41"
+                },
+                Change::Changed {
+                    part: 2,
+                    before: "6",
+                    after: "7"
+                },
+            ]
+        );
+
+        assert_eq!(
+            diff(&Answers::new(), &answers(&[(1, "41")])),
+            [Change::New {
+                part: 1,
+                answer: "41"
+            }]
+        );
+        assert_eq!(
+            diff(&previous, &answers(&[(1, "41")]))[1],
+            Change::Gone {
+                part: 2,
+                before: "6"
+            }
+        );
+    }
+
+    #[test]
+    fn highlights_changed_answers() {
+        let changed = Change::Changed {
+            part: 2,
+            before: "6",
+            after: "7",
+        };
+        assert_eq!(describe(&changed, false), "Part 2: 6 -> 7 (changed)");
+        assert_eq!(
+            describe(&changed, true),
+            "\x1b[1;33mPart 2: 6 -> 7 (changed)\x1b[0m"
+        );
+        let same = Change::Same {
+            part: 1,
+            answer: "41",
+        };
+        assert_eq!(describe(&same, true), "Part 1: 41 (unchanged)");
+    }
+
+    #[test]
+    fn reads_the_json_records() {
+        let stdout = "{\"day\":6,\"part\":1,\"answer\":\"41\",\"parse_ms\":0.1,\"solve_ms\":0.2}\n\
+                      {\"day\":6,\"part\":2,\"answer\":\"6\",\"parse_ms\":0.1,\"solve_ms\":3.0}\n";
+        assert_eq!(
+            parse_answers(stdout).unwrap(),
+            answers(&[(1, "41"), (2, "6")])
+        );
+        assert!(parse_answers("Result (part one): 41\n").is_err());
+    }
+
+    #[test]
+    fn forwards_the_input_options() {
+        let args = InputArgs {
+            example: true,
+            set: vec!["width=11".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(day_args(&args), ["--example", "--set", "width=11"]);
+
+        // The day runs from another directory than the watcher
+        let args = InputArgs {
+            input: Some("input.txt".to_string()),
+            config: Some("../aoc.toml".to_string()),
+            ..Default::default()
+        };
+        let cwd = env::current_dir().unwrap();
+        let expected = |path: &str| {
+            canonical_file(&cwd.join(path))
+                .unwrap()
+                .display()
+                .to_string()
+        };
+        assert_eq!(
+            day_args(&args),
+            [
+                "--input".to_string(),
+                expected("input.txt"),
+                "--config".to_string(),
+                expected("../aoc.toml")
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_the_built_binary() {
+        let stdout = "{\"reason\":\"compiler-artifact\",\"executable\":null}\n\
+                      {\"reason\":\"compiler-artifact\",\"executable\":\"/target/release/y2024_day06\"}\n\
+                      {\"reason\":\"build-finished\",\"success\":true}\n";
+        assert_eq!(
+            executable(stdout),
+            Some(PathBuf::from("/target/release/y2024_day06"))
+        );
+        assert_eq!(executable(""), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn stops_runs_after_the_timeout() {
+        let mut endless = Command::new("sleep");
+        endless.arg("10");
+        assert!(run_with_timeout(endless, Duration::from_millis(100))
+            .unwrap()
+            .is_none());
+
+        let mut echo = Command::new("echo");
+        echo.arg("41");
+        let output = run_with_timeout(echo, Duration::from_secs(10))
+            .unwrap()
+            .unwrap();
+        assert_eq!(output.stdout, b"41\n");
+    }
+}
@@ -0,0 +1,95 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::input::DayFiles;
+
+/// Environment variable pointing to the answers file, overriding [`ANSWERS_FILE`]
+pub const ANSWERS_VAR: &str = "AOC_ANSWERS";
+
+/// Answers file at the root of the workspace, shared by every year
+pub const ANSWERS_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt");
+
+/// Known answers of the real input of a day.
+///
+/// The answers file holds one `<day crate> <part> <answer>` entry per line, e.g. `y2024_day01 2 31`.
+/// Blank lines and lines starting with `#` are ignored.
+pub struct Answers {
+    name: &'static str,
+    parts: [Option<String>; 2],
+}
+
+/// Path of the answers file, see [`ANSWERS_VAR`]
+pub fn path() -> PathBuf {
+    match env::var_os(ANSWERS_VAR) {
+        Some(path) => PathBuf::from(path),
+        None => PathBuf::from(ANSWERS_FILE),
+    }
+}
+
+impl Answers {
+    /// Reads the answers of the day from the answers file
+    pub fn load(files: &DayFiles) -> Result<Self, Box<dyn Error>> {
+        let path = path();
+        let text = fs::read_to_string(&path)
+            .map_err(|e| format!("Cannot read answers '{}': {}", path.display(), e))?;
+        Self::parse(files.name, &text)
+    }
+
+    /// Picks the answers of the day 'name' out of the content of an answers file
+    pub fn parse(name: &'static str, text: &str) -> Result<Self, Box<dyn Error>> {
+        let mut parts = [None, None];
+
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.split_whitespace();
+            let (Some(day), Some(part), Some(answer), None) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(format!("Line {}: expected '<day> <part> <answer>'", i + 1).into());
+            };
+
+            let slot = match part {
+                "1" => 0,
+                "2" => 1,
+                _ => return Err(format!("Line {}: invalid part '{}'", i + 1, part).into()),
+            };
+            if day == name {
+                parts[slot] = Some(answer.to_string());
+            }
+        }
+
+        Ok(Answers { name, parts })
+    }
+
+    /// Recorded answer of the part, if any
+    pub fn get(&self, part: u8) -> Option<&str> {
+        self.parts
+            .get(usize::from(part).checked_sub(1)?)?
+            .as_deref()
+    }
+
+    /// Asserts that 'answer' matches the recorded answer of the part, skipping parts without one
+    #[track_caller]
+    pub fn check(&self, part: u8, answer: impl Display) {
+        match self.get(part) {
+            Some(expected) => assert_eq!(
+                answer.to_string(),
+                expected,
+                "Wrong answer for {} part {}",
+                self.name,
+                part
+            ),
+            None => eprintln!(
+                "No recorded answer for {} part {}, skipping",
+                self.name, part
+            ),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+
+use serde::de::DeserializeOwned;
+use toml::{Table, Value};
+
+use crate::input::{DayFiles, InputArgs};
+
+/// Environment variable pointing to the configuration file, when `--config` is not given
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+/// Configuration file read by default, at the root of the workspace
+pub const DEFAULT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../aoc.toml");
+
+/// Loads the parameters of a day from the table of the configuration file named after its crate,
+/// e.g. `[y2024_day14]`.
+///
+/// The file is the `--config` path, or the one in [`CONFIG_VAR`], or [`DEFAULT_PATH`] if it exists.
+/// With `--example`, the keys of the `[y2024_day14.example]` table replace those of `[y2024_day14]`,
+/// and the `--set` options replace both. Missing keys keep the default parameters of the real puzzle.
+pub fn load<T: DeserializeOwned>(files: &DayFiles, args: &InputArgs) -> Result<T, Box<dyn Error>> {
+    let text = match args.config.clone().or_else(|| env::var(CONFIG_VAR).ok()) {
+        Some(path) => fs::read_to_string(&path)
+            .map_err(|e| format!("Cannot read the config file '{}': {}", path, e))?,
+        None => fs::read_to_string(DEFAULT_PATH).unwrap_or_default(),
+    };
+    section(&text, files.name, args.example, &args.set)
+}
+
+/// Parameters of 'day' in the configuration file 'text', see [`load`]
+fn section<T: DeserializeOwned>(
+    text: &str,
+    day: &str,
+    example: bool,
+    overrides: &[String],
+) -> Result<T, Box<dyn Error>> {
+    let mut file = text
+        .parse::<Table>()
+        .map_err(|e| format!("Invalid config file: {}", e.to_string().trim_end()))?;
+    let mut section = match file.remove(day) {
+        Some(Value::Table(section)) => section,
+        Some(_) => return Err(format!("[{}] must be a table in the config file", day).into()),
+        None => Table::new(),
+    };
+
+    match section.remove("example") {
+        Some(Value::Table(example_section)) if example => section.extend(example_section),
+        Some(Value::Table(_)) | None => {}
+        Some(_) => {
+            return Err(format!("[{}.example] must be a table in the config file", day).into())
+        }
+    }
+
+    for option in overrides {
+        let (key, value) = option
+            .split_once('=')
+            .ok_or_else(|| format!("Expected KEY=VALUE in --set, found '{}'", option))?;
+        // Overrides of the other days are skipped, so that the runner can pass them all
+        let key = match key.trim().split_once('.') {
+            Some((key_day, key)) if key_day == day => key,
+            Some(_) => continue,
+            None => key.trim(),
+        };
+        section.insert(key.to_string(), parse_value(value.trim()));
+    }
+
+    Value::Table(section)
+        .try_into()
+        .map_err(|e| format!("Invalid [{}] config: {}", day, e.to_string().trim_end()).into())
+}
+
+/// TOML value written on the command line, unquoted strings are accepted as such
+fn parse_value(value: &str) -> Value {
+    format!("value = {}", value)
+        .parse::<Table>()
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| Value::String(value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(default, deny_unknown_fields)]
+    struct Config {
+        width: i32,
+        height: i32,
+        word: String,
+    }
+
+    impl Default for Config {
+        fn default() -> Self {
+            Config {
+                width: 101,
+                height: 103,
+                word: "XMAS".to_string(),
+            }
+        }
+    }
+
+    const FILE: &str = "
+        [day_test]
+        width = 50
+
+        [day_test.example]
+        width = 11
+        height = 7
+
+        [day_other]
+        width = 1
+    ";
+
+    #[test]
+    fn merges_the_example_table() {
+        let config: Config = section(FILE, "day_test", false, &[]).unwrap();
+        assert_eq!((config.width, config.height), (50, 103));
+
+        let config: Config = section(FILE, "day_test", true, &[]).unwrap();
+        assert_eq!((config.width, config.height), (11, 7));
+
+        assert_eq!(
+            section::<Config>("", "day_test", true, &[]).unwrap(),
+            Config::default()
+        );
+    }
+
+    #[test]
+    fn applies_the_overrides() {
+        let overrides = [
+            "height=9".to_string(),
+            "day_test.word = SAMX".to_string(),
+            "day_other.width=3".to_string(),
+        ];
+        let config: Config = section(FILE, "day_test", true, &overrides).unwrap();
+        assert_eq!(
+            config,
+            Config {
+                width: 11,
+                height: 9,
+                word: "SAMX".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        let error = section::<Config>(FILE, "day_test", false, &["depth=1".to_string()]);
+        assert!(error
+            .unwrap_err()
+            .to_string()
+            .contains("unknown field `depth`"));
+
+        let error = section::<Config>(FILE, "day_test", false, &["width=wide".to_string()]);
+        assert!(error
+            .unwrap_err()
+            .to_string()
+            .starts_with("Invalid [day_test] config"));
+
+        let error = section::<Config>(FILE, "day_test", false, &["width".to_string()]);
+        assert!(error.unwrap_err().to_string().contains("KEY=VALUE"));
+    }
+}
@@ -0,0 +1,241 @@
+use std::fmt::{self, Display};
+use std::io::{self, BufRead};
+use std::process::ExitCode;
+use std::str::FromStr;
+
+/// Error raised while reading, parsing or solving the input of a day.
+///
+/// Each variant carries the name of the day crate it comes from, e.g. `y2024_day02`.
+#[derive(Debug)]
+pub enum Error {
+    /// The input cannot be read
+    Io {
+        day: &'static str,
+        source: io::Error,
+    },
+    /// The input is malformed at the given line and column, both starting from 1
+    Parse {
+        day: &'static str,
+        line: usize,
+        column: usize,
+        text: String,
+        message: String,
+    },
+    /// The input is well formed but cannot be solved as a whole
+    Invalid { day: &'static str, message: String },
+}
+
+/// Result of reading, parsing or solving the input of a day
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// Builds an error about the input as a whole
+    pub fn invalid(day: &'static str, message: impl Into<String>) -> Self {
+        Error::Invalid {
+            day,
+            message: message.into(),
+        }
+    }
+
+    /// Name of the day crate raising the error
+    pub fn day(&self) -> &'static str {
+        match self {
+            Error::Io { day, .. } | Error::Parse { day, .. } | Error::Invalid { day, .. } => day,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { day, source } => write!(f, "{}: cannot read input: {}", day, source),
+            Error::Parse {
+                day,
+                line,
+                column,
+                text,
+                message,
+            } => {
+                write!(f, "{}: line {}, column {}: ", day, line, column)?;
+                if !text.is_empty() {
+                    write!(f, "'{}': ", text)?;
+                }
+                write!(f, "{}", message)
+            }
+            Error::Invalid { day, message } => write!(f, "{}: {}", day, message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Line of the input of a day, locating the errors found in it
+pub struct Line {
+    pub day: &'static str,
+    /// Line number, starting from 1
+    pub number: usize,
+    pub text: String,
+}
+
+impl Line {
+    /// Column of 'token', a slice of the text of the line, starting from 1.
+    ///
+    /// A token copied out of the line is located by its first occurrence, at column 1 if not found.
+    pub fn column(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset + token.len() <= self.text.len())
+            .or_else(|| self.text.find(token))
+            .unwrap_or(0);
+        self.text[..offset].chars().count() + 1
+    }
+
+    /// Builds an error about 'token', a slice of the text of the line
+    pub fn error(&self, token: &str, message: impl Into<String>) -> Error {
+        self.error_at(self.column(token), token, message)
+    }
+
+    /// Builds an error about 'text' found at 'column', starting from 1
+    pub fn error_at(&self, column: usize, text: &str, message: impl Into<String>) -> Error {
+        Error::Parse {
+            day: self.day,
+            line: self.number,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Builds an error about something missing at the end of the line
+    pub fn missing(&self, what: &str) -> Error {
+        self.error_at(
+            self.text.chars().count() + 1,
+            "",
+            format!("missing {}", what),
+        )
+    }
+
+    /// Builds an error about a row of a grid not being 'expected' cells wide
+    pub fn wrong_width(&self, expected: usize) -> Error {
+        let found = self.text.chars().count();
+        let text = self
+            .text
+            .chars()
+            .nth(expected)
+            .map(String::from)
+            .unwrap_or_default();
+        self.error_at(
+            found.min(expected) + 1,
+            &text,
+            format!("expected {} cells, found {}", expected, found),
+        )
+    }
+
+    /// Parses 'token', a slice of the text of the line
+    pub fn parse<T: FromStr>(&self, token: &str) -> Result<T>
+    where
+        T::Err: Display,
+    {
+        token
+            .parse()
+            .map_err(|e: T::Err| self.error(token, e.to_string()))
+    }
+
+    /// Iterates over the characters of the line along with their column
+    pub fn chars(&self) -> impl Iterator<Item = (usize, char)> + '_ {
+        self.text.chars().enumerate().map(|(i, c)| (i + 1, c))
+    }
+}
+
+/// Reads the lines of the input of the day 'day'
+pub fn lines(day: &'static str, reader: impl BufRead) -> impl Iterator<Item = Result<Line>> {
+    reader.lines().enumerate().map(move |(i, line)| match line {
+        Ok(text) => Ok(Line {
+            day,
+            number: i + 1,
+            text,
+        }),
+        Err(source) => Err(Error::Io { day, source }),
+    })
+}
+
+/// Prints the error ending a day binary, if any, and turns it into the exit code
+pub fn report(result: std::result::Result<(), Box<dyn std::error::Error>>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str) -> Line {
+        Line {
+            day: "day_test",
+            number: 3,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn columns_count_characters() {
+        let line = line("ab été 42");
+        let token = line.text.split_whitespace().nth(2).unwrap();
+        assert_eq!(line.column(token), 8);
+        assert_eq!(line.column(&line.text[..0]), 1);
+        // Tokens that are not slices of the line are searched for
+        assert_eq!(line.column("été"), 4);
+        assert_eq!(line.column("xyz"), 1);
+    }
+
+    #[test]
+    fn messages_locate_the_problem() {
+        let line = line("7 é x");
+        let error = line.parse::<u8>(&line.text[5..]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day_test: line 3, column 5: 'x': invalid digit found in string"
+        );
+        assert_eq!(
+            line.missing("level").to_string(),
+            "day_test: line 3, column 6: missing level"
+        );
+        assert_eq!(
+            line.wrong_width(3).to_string(),
+            "day_test: line 3, column 4: ' ': expected 3 cells, found 5"
+        );
+        assert_eq!(
+            line.wrong_width(8).to_string(),
+            "day_test: line 3, column 6: expected 8 cells, found 5"
+        );
+        assert_eq!(
+            Error::invalid("day_test", "the map is empty").to_string(),
+            "day_test: the map is empty"
+        );
+    }
+
+    #[test]
+    fn numbers_lines_from_one() {
+        let lines: Vec<Line> = lines("day_test", "a\n\nb\n".as_bytes())
+            .collect::<Result<_>>()
+            .unwrap();
+        let numbers: Vec<(usize, &str)> = lines
+            .iter()
+            .map(|line| (line.number, line.text.as_str()))
+            .collect();
+        assert_eq!(numbers, [(1, "a"), (2, ""), (3, "b")]);
+    }
+}
@@ -0,0 +1,282 @@
+use std::env;
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use crate::output::Format;
+
+/// Environment variable pointing to a directory holding the real inputs, laid out as `<dir>/<day crate>/input.txt`
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Name of the real input file of every day
+pub const INPUT_FILE: &str = "input.txt";
+
+/// Bundled input files of a day crate, see [`day_files!`](crate::day_files)
+pub struct DayFiles {
+    /// Year of the puzzle
+    pub year: u16,
+    /// Day of the puzzle, from 1 to 25
+    pub day: u8,
+    /// Name of the day crate, e.g. `y2024_day01`
+    pub name: &'static str,
+    /// Manifest directory of the day crate, the inputs are stored in its `src` directory
+    pub manifest_dir: &'static str,
+    /// Name of the example input file
+    pub example: &'static str,
+}
+
+/// Declares the bundled input files of the calling day crate, solving the puzzle of day '$day' of
+/// '$year'
+#[macro_export]
+macro_rules! day_files {
+    ($year:expr, $day:expr, $example:expr) => {
+        $crate::input::DayFiles {
+            year: $year,
+            day: $day,
+            name: env!("CARGO_PKG_NAME"),
+            manifest_dir: env!("CARGO_MANIFEST_DIR"),
+            example: $example,
+        }
+    };
+}
+
+/// Where the input of a day is read from
+#[derive(Debug)]
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+}
+
+/// Command line options of the day binaries, selecting their input and output
+#[derive(Debug, Default)]
+pub struct InputArgs {
+    /// Path given with `--input`, `-` stands for stdin
+    pub input: Option<String>,
+    /// Use the bundled example input instead of the real one
+    pub example: bool,
+    /// Format of the answers
+    pub format: Format,
+    /// File receiving the visualizations, if any
+    pub visualization: Option<String>,
+    /// Configuration file given with `--config`, see [`config::load`](crate::config::load)
+    pub config: Option<String>,
+    /// Parameters overridden with `--set KEY=VALUE`
+    pub set: Vec<String>,
+}
+
+/// Usage of the day binaries
+pub const USAGE: &str = "Options:
+  --input <PATH>          Read the puzzle input from PATH, or from stdin if PATH is -
+  --example               Use the bundled example input
+  --format <FORMAT>       Print the answers as text or json [default: text]
+  --visualization <PATH>  Write the visualizations to PATH instead of stdout (stderr with --format json)
+  --config <PATH>         Read the puzzle parameters from PATH [default: $AOC_CONFIG, or aoc.toml at the workspace root]
+  --set <KEY=VALUE>       Override a puzzle parameter of the config file, e.g. --set width=11, can be repeated
+  -h, --help              Print help";
+
+impl InputArgs {
+    /// Parses the options listed in [`USAGE`], values are given as `--option <VALUE>` or `--option=<VALUE>`
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, Box<dyn Error>> {
+        let mut input_args = InputArgs::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            if arg == "--example" {
+                input_args.example = true;
+                continue;
+            }
+
+            let (option, value) = match arg.split_once('=') {
+                Some((option, value)) => (option, Some(value.to_string())),
+                None => (arg.as_str(), None),
+            };
+            let value = || {
+                value
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("Missing value for {}", option))
+            };
+
+            match option {
+                "--input" => input_args.input = Some(value()?),
+                "--format" => input_args.format = value()?.parse()?,
+                "--visualization" => input_args.visualization = Some(value()?),
+                "--config" => input_args.config = Some(value()?),
+                "--set" => input_args.set.push(value()?),
+                _ => return Err(format!("Unexpected argument '{}'\n\n{}", arg, USAGE).into()),
+            }
+        }
+
+        if input_args.input.is_some() && input_args.example {
+            return Err("--input and --example cannot be used together".into());
+        }
+
+        Ok(input_args)
+    }
+
+    /// Whether the real input of the day is selected, the only one whose answers the ledger holds
+    pub fn is_real(&self) -> bool {
+        self.input.is_none() && !self.example
+    }
+
+    /// Parses the arguments of the running binary, printing the usage on `-h`/`--help`
+    pub fn from_env() -> Result<Self, Box<dyn Error>> {
+        let args: Vec<String> = env::args().skip(1).collect();
+        if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+            println!("{}", USAGE);
+            std::process::exit(0);
+        }
+        Self::parse(args)
+    }
+}
+
+/// Resolves where the input of a day should be read from.
+///
+/// In order of priority: the `--input` path (stdin for `-`), the bundled example with `--example`,
+/// the day's directory inside [`INPUT_DIR_VAR`], and finally the `src` directory of the day crate.
+pub fn resolve(files: &DayFiles, args: &InputArgs) -> Source {
+    resolve_in(
+        files,
+        args,
+        env::var_os(INPUT_DIR_VAR).as_deref().map(Path::new),
+    )
+}
+
+/// Same as [`resolve`], with 'input_dir' in place of the directory of [`INPUT_DIR_VAR`]
+fn resolve_in(files: &DayFiles, args: &InputArgs, input_dir: Option<&Path>) -> Source {
+    match args.input.as_deref() {
+        Some("-") => return Source::Stdin,
+        Some(path) => return Source::Path(PathBuf::from(path)),
+        None => {}
+    }
+
+    let src_dir = Path::new(files.manifest_dir).join("src");
+    if args.example {
+        return Source::Path(src_dir.join(files.example));
+    }
+
+    match input_dir {
+        Some(dir) => Source::Path(dir.join(files.name).join(INPUT_FILE)),
+        None => Source::Path(src_dir.join(INPUT_FILE)),
+    }
+}
+
+/// Opens the input source for reading
+pub fn open(source: &Source) -> Result<Box<dyn BufRead>, Box<dyn Error>> {
+    match source {
+        Source::Stdin => Ok(Box::new(io::stdin().lock())),
+        Source::Path(path) => {
+            let file = File::open(path)
+                .map_err(|e| format!("Cannot open input '{}': {}", path.display(), e))?;
+            Ok(Box::new(BufReader::new(file)))
+        }
+    }
+}
+
+/// Opens the bundled example input of the day
+pub fn example(files: &DayFiles) -> Result<Box<dyn BufRead>, Box<dyn Error>> {
+    let args = InputArgs {
+        example: true,
+        ..Default::default()
+    };
+    open(&resolve(files, &args))
+}
+
+/// Opens the real input of the day, see [`resolve`]
+pub fn real(files: &DayFiles) -> Result<Box<dyn BufRead>, Box<dyn Error>> {
+    open(&resolve(files, &InputArgs::default()))
+}
+
+/// Resolves and opens the input selected by 'args'
+pub fn select(files: &DayFiles, args: &InputArgs) -> Result<Box<dyn BufRead>, Box<dyn Error>> {
+    open(&resolve(files, args))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::io::Read;
+
+    fn args(args: &[&str]) -> Result<InputArgs, Box<dyn Error>> {
+        InputArgs::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn read(source: Source) -> String {
+        let mut text = String::new();
+        open(&source).unwrap().read_to_string(&mut text).unwrap();
+        text
+    }
+
+    #[test]
+    fn parses_options() {
+        let parsed = args(&["--input=a.txt", "--format", "json", "--set", "width=11"]).unwrap();
+        assert_eq!(parsed.input.as_deref(), Some("a.txt"));
+        assert_eq!(parsed.format, Format::Json);
+        assert_eq!(parsed.set, ["width=11"]);
+        assert!(args(&["--example"]).unwrap().example);
+
+        // Only the real input is checked against the ledger
+        assert!(args(&[]).unwrap().is_real());
+        assert!(!args(&["--example"]).unwrap().is_real());
+        assert!(!args(&["--input", "custom.txt"]).unwrap().is_real());
+
+        let error = |list: &[&str]| args(list).unwrap_err().to_string();
+        assert!(error(&["--day", "3"]).starts_with("Unexpected argument '--day'"));
+        assert_eq!(error(&["--input"]), "Missing value for --input");
+        assert_eq!(
+            error(&["--input", "a.txt", "--example"]),
+            "--input and --example cannot be used together"
+        );
+    }
+
+    #[test]
+    fn resolves_inputs_in_order_of_priority() {
+        let dir = env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        let write = |path: &str, text: &str| {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, text).unwrap();
+        };
+        write("day/src/input.txt", "bundled");
+        write("day/src/example.txt", "example");
+        write("inputs/day_test/input.txt", "input dir");
+        write("custom.txt", "custom");
+
+        let files = DayFiles {
+            year: 2024,
+            day: 1,
+            name: "day_test",
+            manifest_dir: String::leak(dir.join("day").display().to_string()),
+            example: "example.txt",
+        };
+        let custom = dir.join("custom.txt").display().to_string();
+
+        // The directory is given rather than set in the environment, which the tests share
+        let resolve = |list: &[&str], input_dir: Option<&Path>| {
+            resolve_in(&files, &args(list).unwrap(), input_dir)
+        };
+        assert_eq!(read(resolve(&[], None)), "bundled");
+        assert_eq!(read(resolve(&["--example"], None)), "example");
+
+        let inputs = dir.join("inputs");
+        assert_eq!(read(resolve(&[], Some(&inputs))), "input dir");
+        assert_eq!(read(resolve(&["--example"], Some(&inputs))), "example");
+        assert_eq!(
+            read(resolve(&["--input", &custom], Some(&inputs))),
+            "custom"
+        );
+        assert!(matches!(
+            resolve(&["--input", "-"], Some(&inputs)),
+            Source::Stdin
+        ));
+
+        let missing = select(&files, &args(&["--input", "missing.txt"]).unwrap());
+        assert!(missing
+            .err()
+            .unwrap()
+            .to_string()
+            .starts_with("Cannot open input 'missing.txt'"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,427 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Environment variable pointing to the ledger, overriding [`LEDGER_FILE`]
+pub const LEDGER_VAR: &str = "AOC_LEDGER";
+
+/// Ledger at the root of the workspace, shared by every year
+pub const LEDGER_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../ledger.toml");
+
+/// What the website answered to a submitted answer
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Rejected without telling whether it was too high or too low
+    Wrong,
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            _ => Err(format!(
+                "Unknown verdict '{}', expected 'correct', 'too-high', 'too-low' or 'wrong'",
+                s
+            )),
+        }
+    }
+}
+
+/// Answers submitted for a part, with what the website answered
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Guesses {
+    /// Answer the website accepted, only used to warn about a different one. The answers the tests
+    /// check are those of the answers file, see [`crate::answers`]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "optional_answer"
+    )]
+    pub correct: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", deserialize_with = "answers")]
+    pub too_high: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", deserialize_with = "answers")]
+    pub too_low: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", deserialize_with = "answers")]
+    pub wrong: Vec<String>,
+}
+
+impl Guesses {
+    fn is_empty(&self) -> bool {
+        *self == Guesses::default()
+    }
+
+    /// Why 'answer' cannot be the answer of the part, if it contradicts a previous submission.
+    ///
+    /// Numeric answers are also compared to the lowest answer that was too high and to the highest
+    /// answer that was too low.
+    pub fn check(&self, answer: &str) -> Option<String> {
+        if self.correct.as_deref() == Some(answer) {
+            return None;
+        }
+        let rejected = |guesses: &[String]| guesses.iter().any(|guess| guess == answer);
+        if rejected(&self.too_high) {
+            return Some(format!("{} was already rejected as too high", answer));
+        }
+        if rejected(&self.too_low) {
+            return Some(format!("{} was already rejected as too low", answer));
+        }
+        if rejected(&self.wrong) {
+            return Some(format!("{} was already rejected", answer));
+        }
+        if let Some(correct) = &self.correct {
+            return Some(format!(
+                "{} differs from the correct answer {}",
+                answer, correct
+            ));
+        }
+
+        // Answers that are not numbers can only be compared as a whole
+        let value = answer.parse::<i128>().ok()?;
+        let numbers = |guesses: &[String]| {
+            guesses
+                .iter()
+                .filter_map(|guess| guess.parse::<i128>().ok())
+                .collect::<Vec<_>>()
+        };
+        if let Some(high) = numbers(&self.too_high).into_iter().min() {
+            if value >= high {
+                return Some(format!(
+                    "{} is not below {}, which was too high",
+                    answer, high
+                ));
+            }
+        }
+        if let Some(low) = numbers(&self.too_low).into_iter().max() {
+            if value <= low {
+                return Some(format!(
+                    "{} is not above {}, which was too low",
+                    answer, low
+                ));
+            }
+        }
+        None
+    }
+
+    fn record(&mut self, answer: &str, verdict: Verdict) -> Result<(), String> {
+        if verdict != Verdict::Correct && self.correct.as_deref() == Some(answer) {
+            return Err(format!("{} is recorded as the correct answer", answer));
+        }
+        let list = match verdict {
+            Verdict::Correct => {
+                if let Some(correct) = self.correct.as_ref().filter(|correct| *correct != answer) {
+                    return Err(format!("The correct answer is already {}", correct));
+                }
+                // A correct answer is never rejected, an earlier verdict was recorded by mistake
+                for list in [&mut self.too_high, &mut self.too_low, &mut self.wrong] {
+                    list.retain(|guess| guess != answer);
+                }
+                self.correct = Some(answer.to_string());
+                return Ok(());
+            }
+            Verdict::TooHigh => &mut self.too_high,
+            Verdict::TooLow => &mut self.too_low,
+            Verdict::Wrong => &mut self.wrong,
+        };
+        if !list.iter().any(|guess| guess == answer) {
+            list.push(answer.to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Submissions of both parts of a day
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Day {
+    #[serde(skip_serializing_if = "Guesses::is_empty")]
+    part_one: Guesses,
+    #[serde(skip_serializing_if = "Guesses::is_empty")]
+    part_two: Guesses,
+}
+
+/// Answers submitted to the website, with what it answered, so that the solutions are warned
+/// before an answer is submitted twice.
+///
+/// The ledger has one table per part of each day crate, e.g.
+/// ```toml
+/// [y2024_day13.part_two]
+/// correct = "108713182988244"
+/// too_high = ["170141183460469231731687303715884105727"]
+/// too_low = ["875318608908"]
+/// ```
+/// Answers can be written as strings or as integers.
+///
+/// The answers file, see [`crate::answers`], stays the source of truth of the correct answers: the
+/// ledger records what the website answered, the answers file what the tests expect.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Ledger {
+    days: BTreeMap<String, Day>,
+}
+
+/// Path of the ledger, see [`LEDGER_VAR`]
+pub fn path() -> PathBuf {
+    match env::var_os(LEDGER_VAR) {
+        Some(path) => PathBuf::from(path),
+        None => PathBuf::from(LEDGER_FILE),
+    }
+}
+
+impl Ledger {
+    /// Reads the ledger at 'path', which is empty if the file does not exist
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(text) => text
+                .parse()
+                .map_err(|e| format!("Invalid ledger '{}': {}", path.display(), e).into()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(e) => Err(format!("Cannot read the ledger '{}': {}", path.display(), e).into()),
+        }
+    }
+
+    /// Writes the ledger to 'path', comments of the previous file are not kept
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        let text = toml::to_string(self)?;
+        fs::write(path, text)
+            .map_err(|e| format!("Cannot write the ledger '{}': {}", path.display(), e).into())
+    }
+
+    /// Submissions of a part of the day 'name', if any
+    pub fn get(&self, name: &str, part: u8) -> Option<&Guesses> {
+        let day = self.days.get(name)?;
+        match part {
+            1 => Some(&day.part_one),
+            2 => Some(&day.part_two),
+            _ => None,
+        }
+    }
+
+    /// Why 'answer' cannot be the answer of a part of the day 'name', see [`Guesses::check`]
+    pub fn check(&self, name: &str, part: u8, answer: &str) -> Option<String> {
+        self.get(name, part)?.check(answer)
+    }
+
+    /// Prints to stderr why 'answer' cannot be the answer of a part of the day 'name', if the
+    /// ledger says so
+    pub fn warn(&self, name: &str, part: u8, answer: &str) {
+        if let Some(warning) = self.check(name, part, answer) {
+            eprintln!("Warning: {} part {}: {}", name, part, warning);
+        }
+    }
+
+    /// Records what the website answered to 'answer', refusing verdicts contradicting the correct
+    /// answer
+    pub fn record(
+        &mut self,
+        name: &str,
+        part: u8,
+        answer: &str,
+        verdict: Verdict,
+    ) -> Result<(), String> {
+        let answer = answer.trim();
+        if answer.is_empty() || answer.contains(char::is_whitespace) {
+            return Err(format!("Invalid answer '{}'", answer));
+        }
+        let day = self.days.entry(name.to_string()).or_default();
+        let guesses = match part {
+            1 => &mut day.part_one,
+            2 => &mut day.part_two,
+            _ => return Err(format!("Part {} does not exist", part)),
+        };
+        guesses.record(answer, verdict)
+    }
+}
+
+impl FromStr for Ledger {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        toml::from_str(s).map_err(|e| e.to_string().trim_end().to_string())
+    }
+}
+
+/// Prints to stderr why 'answer' cannot be the answer of a part of the day 'name', if the ledger
+/// says so.
+///
+/// The ledger is read once by the process, on the first answer, and a ledger that cannot be read
+/// is reported once.
+pub fn warn(name: &str, part: u8, answer: &str) {
+    static LEDGER: OnceLock<Option<Ledger>> = OnceLock::new();
+
+    let ledger = LEDGER.get_or_init(|| {
+        Ledger::load(&path())
+            .map_err(|e| eprintln!("Warning: {}", e))
+            .ok()
+    });
+    if let Some(ledger) = ledger {
+        ledger.warn(name, part, answer);
+    }
+}
+
+/// Answer written in the ledger, as a string or as an integer
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Written {
+    Text(String),
+    Number(i64),
+}
+
+impl From<Written> for String {
+    fn from(written: Written) -> Self {
+        match written {
+            Written::Text(text) => text,
+            Written::Number(number) => number.to_string(),
+        }
+    }
+}
+
+fn optional_answer<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    Ok(Some(Written::deserialize(deserializer)?.into()))
+}
+
+fn answers<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    let written = Vec::<Written>::deserialize(deserializer)?;
+    Ok(written.into_iter().map(String::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEDGER: &str = r#"
+        [y2024_day13.part_two]
+        too_high = ["170141183460469231731687303715884105727", 200000000000000]
+        too_low = [875318608908]
+        wrong = ["108713182988243"]
+
+        [y2024_day01.part_one]
+        correct = 2904518
+    "#;
+
+    #[test]
+    fn reads_strings_and_integers() {
+        let ledger: Ledger = LEDGER.parse().unwrap();
+        let guesses = ledger.get("y2024_day13", 2).unwrap();
+        assert_eq!(guesses.too_high[1], "200000000000000");
+        assert_eq!(guesses.too_low, ["875318608908"]);
+        assert_eq!(
+            ledger.get("y2024_day01", 1).unwrap().correct.as_deref(),
+            Some("2904518")
+        );
+        assert_eq!(ledger.get("y2024_day13", 1), Some(&Guesses::default()));
+        assert_eq!(ledger.get("y2024_day02", 1), None);
+
+        assert!("[y2024_day01.part_three]".parse::<Ledger>().is_err());
+        assert!("[y2024_day01.part_one]\ncorrect = 1.5"
+            .parse::<Ledger>()
+            .is_err());
+    }
+
+    #[test]
+    fn warns_about_rejected_answers() {
+        let ledger: Ledger = LEDGER.parse().unwrap();
+        let check = |answer: &str| ledger.check("y2024_day13", 2, answer);
+
+        // The sentinel of a machine without a solution leaking into the total
+        assert_eq!(
+            check(&i128::MAX.to_string()).unwrap(),
+            "170141183460469231731687303715884105727 was already rejected as too high"
+        );
+        assert_eq!(
+            check("875318608908").unwrap(),
+            "875318608908 was already rejected as too low"
+        );
+        assert_eq!(
+            check("108713182988243").unwrap(),
+            "108713182988243 was already rejected"
+        );
+        assert_eq!(
+            check("300000000000000").unwrap(),
+            "300000000000000 is not below 200000000000000, which was too high"
+        );
+        assert_eq!(
+            check("12").unwrap(),
+            "12 is not above 875318608908, which was too low"
+        );
+        assert_eq!(check("108713182988244"), None);
+        assert_eq!(check("not a number"), None);
+
+        assert_eq!(ledger.check("y2024_day01", 1, "2904518"), None);
+        assert_eq!(
+            ledger.check("y2024_day01", 1, "2904519").unwrap(),
+            "2904519 differs from the correct answer 2904518"
+        );
+        assert_eq!(ledger.check("y2024_day01", 2, "1"), None);
+    }
+
+    #[test]
+    fn records_the_verdicts() {
+        let mut ledger = Ledger::default();
+        ledger
+            .record("y2024_day13", 2, "1000", Verdict::TooLow)
+            .unwrap();
+        ledger
+            .record("y2024_day13", 2, "1000", Verdict::TooLow)
+            .unwrap();
+        ledger
+            .record("y2024_day13", 2, "9000", Verdict::TooHigh)
+            .unwrap();
+        ledger
+            .record("y2024_day13", 2, "4000", Verdict::Wrong)
+            .unwrap();
+        ledger
+            .record("y2024_day13", 2, "4000", Verdict::Correct)
+            .unwrap();
+
+        let guesses = ledger.get("y2024_day13", 2).unwrap();
+        assert_eq!(guesses.too_low, ["1000"]);
+        assert_eq!(guesses.too_high, ["9000"]);
+        assert!(guesses.wrong.is_empty());
+        assert_eq!(guesses.correct.as_deref(), Some("4000"));
+
+        assert!(ledger
+            .record("y2024_day13", 2, "4000", Verdict::TooHigh)
+            .is_err());
+        assert!(ledger
+            .record("y2024_day13", 2, "4001", Verdict::Correct)
+            .is_err());
+        assert!(ledger
+            .record("y2024_day13", 3, "1", Verdict::Wrong)
+            .is_err());
+        assert!(ledger
+            .record("y2024_day13", 1, "1 2", Verdict::Wrong)
+            .is_err());
+    }
+
+    #[test]
+    fn saves_what_it_loads() {
+        let mut ledger = Ledger::default();
+        ledger
+            .record("y2024_day13", 2, "1000", Verdict::TooLow)
+            .unwrap();
+        ledger
+            .record("y2024_day01", 1, "11", Verdict::Correct)
+            .unwrap();
+
+        let text = toml::to_string(&ledger).unwrap();
+        assert!(text.contains("[y2024_day13.part_two]"), "{}", text);
+        assert!(!text.contains("[y2024_day01.part_two]"), "{}", text);
+        assert_eq!(text.parse::<Ledger>().unwrap(), ledger);
+    }
+}
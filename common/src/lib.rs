@@ -0,0 +1,13 @@
+/*
+    Utilities shared by the solutions of every day
+*/
+pub mod answers;
+pub mod config;
+pub mod error;
+pub mod input;
+pub mod ledger;
+pub mod lint;
+pub mod output;
+pub mod random;
+pub mod solution;
+pub mod trace;
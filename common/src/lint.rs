@@ -0,0 +1,208 @@
+use std::fmt::Display;
+use std::io::BufRead;
+
+use crate::error::{self, Error, Line, Result};
+
+/// Runs 'checks' on every line of the input, an input that cannot be read is the only problem reported
+pub fn check(
+    day: &'static str,
+    reader: impl BufRead,
+    checks: impl FnOnce(&[Line]) -> Vec<Error>,
+) -> Vec<Error> {
+    match error::lines(day, reader).collect::<Result<Vec<_>>>() {
+        Ok(lines) => checks(&lines),
+        Err(e) => vec![e],
+    }
+}
+
+/// Checks an input made of independent lines by parsing each line on its own with 'parse', so
+/// that every malformed line is reported and not only the first one.
+///
+/// Each line is parsed along with its line ending, so that an empty line reaches 'parse' as an
+/// empty line and not as an empty input: it is a problem unless the parser of the day accepts it.
+pub fn each_line<T>(lines: &[Line], parse: impl Fn(&[u8]) -> Result<T>) -> Vec<Error> {
+    lines
+        .iter()
+        .filter_map(|line| {
+            let text = format!("{}\n", line.text);
+            parse(text.as_bytes()).err().map(|e| on_line(e, line))
+        })
+        .collect()
+}
+
+/// Moves an error raised while parsing 'line' on its own to the line number of 'line'
+fn on_line(error: Error, line: &Line) -> Error {
+    match error {
+        Error::Parse {
+            day,
+            column,
+            text,
+            message,
+            ..
+        } => Error::Parse {
+            day,
+            line: line.number,
+            column,
+            text,
+            message,
+        },
+        Error::Invalid { message, .. } => line.error_at(1, "", message),
+        e => e,
+    }
+}
+
+/// Checks an input made of a single line with 'parse', reporting the lines following it
+pub fn single_line<T>(lines: &[Line], parse: impl Fn(&[u8]) -> Result<T>) -> Vec<Error> {
+    let first = lines.first().map_or("", |line| line.text.as_str());
+    let mut problems: Vec<Error> = parse(first.as_bytes()).err().into_iter().collect();
+    problems.extend(
+        lines
+            .iter()
+            .skip(1)
+            .filter(|line| !line.text.trim().is_empty())
+            .map(|line| line.error(&line.text, "expected a single line")),
+    );
+    problems
+}
+
+/// Checks that 'lines' form a rectangular grid of at least one cell, made of the cells accepted
+/// by 'is_cell', described as 'expected' in the errors
+pub fn grid(
+    day: &'static str,
+    lines: &[Line],
+    is_cell: impl Fn(char) -> bool,
+    expected: &str,
+) -> Vec<Error> {
+    let Some(first) = lines.first() else {
+        return vec![Error::invalid(day, "the map is empty")];
+    };
+    let width = first.text.chars().count();
+    if width == 0 {
+        return vec![first.missing("cell")];
+    }
+
+    let mut problems = Vec::new();
+    for line in lines {
+        if line.text.chars().count() != width {
+            problems.push(line.wrong_width(width));
+        }
+        problems.extend(
+            line.chars()
+                .filter(|&(_, c)| !is_cell(c))
+                .map(|(column, c)| {
+                    line.error_at(column, &c.to_string(), format!("expected {}", expected))
+                }),
+        );
+    }
+    problems
+}
+
+/// Checks that exactly one cell of the grid in 'lines' is accepted by 'is_unique', reporting
+/// every cell after the first one
+pub fn exactly_one(
+    day: &'static str,
+    lines: &[Line],
+    is_unique: impl Fn(char) -> bool,
+    what: &str,
+) -> Vec<Error> {
+    let mut found = lines
+        .iter()
+        .flat_map(|line| line.chars().map(move |(column, c)| (line, column, c)))
+        .filter(|&(_, _, c)| is_unique(c));
+
+    if found.next().is_none() {
+        return vec![Error::invalid(day, format!("the map has no {}", what))];
+    }
+    found
+        .map(|(line, column, c)| line.error_at(column, &c.to_string(), format!("second {}", what)))
+        .collect()
+}
+
+/// Prints the problems found in an input, one per line, followed by their count
+pub fn report(problems: &[Error], input: impl Display) {
+    for problem in problems {
+        println!("{}", problem);
+    }
+    match problems.len() {
+        0 => println!("{}: no problems found", input),
+        1 => println!("{}: 1 problem found", input),
+        n => println!("{}: {} problems found", input, n),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<Line> {
+        error::lines("day_test", text.as_bytes())
+            .collect::<Result<_>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn reports_every_line() {
+        let lines = lines("1\nx\n3\ny\n");
+        let problems = each_line(&lines, |text| {
+            let line = error::lines("day_test", text).next().unwrap()?;
+            line.parse::<u8>(&line.text)
+        });
+
+        let found: Vec<String> = problems.iter().map(Error::to_string).collect();
+        assert_eq!(
+            found,
+            [
+                "day_test: line 2, column 1: 'x': invalid digit found in string",
+                "day_test: line 4, column 1: 'y': invalid digit found in string",
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_empty_lines_rejected_by_the_parser() {
+        let lines = lines("1\n\n3\n");
+        let problems = each_line(&lines, |text| {
+            let line = error::lines("day_test", text).next().unwrap()?;
+            line.parse::<u8>(&line.text)
+        });
+
+        let found: Vec<String> = problems.iter().map(Error::to_string).collect();
+        assert_eq!(
+            found,
+            ["day_test: line 2, column 1: cannot parse integer from empty string"]
+        );
+    }
+
+    #[test]
+    fn reports_ragged_rows_and_invalid_cells() {
+        let problems = grid(
+            "day_test",
+            &lines("..#\n.#\n.x.\n"),
+            |c| ".#".contains(c),
+            "'.' or '#'",
+        );
+
+        let found: Vec<String> = problems.iter().map(Error::to_string).collect();
+        assert_eq!(
+            found,
+            [
+                "day_test: line 2, column 3: expected 3 cells, found 2",
+                "day_test: line 3, column 2: 'x': expected '.' or '#'",
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_missing_and_repeated_cells() {
+        let is_guard = |c| c == '^';
+        assert_eq!(
+            exactly_one("day_test", &lines("..\n..\n"), is_guard, "guard")[0].to_string(),
+            "day_test: the map has no guard"
+        );
+        assert!(exactly_one("day_test", &lines(".^\n..\n"), is_guard, "guard").is_empty());
+        assert_eq!(
+            exactly_one("day_test", &lines(".^\n^.\n"), is_guard, "guard")[0].to_string(),
+            "day_test: line 2, column 1: '^': second guard"
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+
+use crate::input::{DayFiles, InputArgs};
+use crate::ledger;
+
+/// Format of the answers printed by the binaries
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// One human readable line per part
+    #[default]
+    Text,
+    /// One JSON record per part, see [`Record::to_json`]
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Unknown format '{}', expected 'text' or 'json'", s)),
+        }
+    }
+}
+
+/// Heap usage of a step, as counted by the allocator of the runner with `--profile`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Allocations {
+    /// Number of allocations, reallocations included
+    pub count: u64,
+    /// Bytes requested by these allocations
+    pub bytes: u64,
+    /// Most bytes held at once during the step, on top of those held before it
+    pub peak: u64,
+}
+
+impl Allocations {
+    fn to_json(self) -> Value {
+        json!({ "count": self.count, "bytes": self.bytes, "peak": self.peak })
+    }
+}
+
+/// Answer of a single part, along with the time spent parsing the input and solving the part
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+    /// Heap usage of the parse step, when profiled
+    pub parse_allocations: Option<Allocations>,
+    /// Heap usage of the part, when profiled
+    pub solve_allocations: Option<Allocations>,
+}
+
+impl Record {
+    /// Serializes the record as `{"year", "day", "part", "answer", "parse_ms", "solve_ms"}` on a
+    /// single line.
+    ///
+    /// The answer is kept as a string, as some answers do not fit in a JSON number. Profiled records
+    /// also have `parse_allocations` and `solve_allocations`, each as `{"count", "bytes", "peak"}`.
+    pub fn to_json(&self) -> String {
+        let mut record = json!({
+            "year": self.year,
+            "day": self.day,
+            "part": self.part,
+            "answer": self.answer,
+            "parse_ms": round_ms(self.parse),
+            "solve_ms": round_ms(self.solve),
+        });
+        if let Some(allocations) = self.parse_allocations {
+            record["parse_allocations"] = allocations.to_json();
+        }
+        if let Some(allocations) = self.solve_allocations {
+            record["solve_allocations"] = allocations.to_json();
+        }
+        record.to_string()
+    }
+}
+
+/// Converts a duration to fractional milliseconds
+pub fn as_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Converts a duration to milliseconds, keeping microsecond precision
+fn round_ms(duration: Duration) -> f64 {
+    (as_ms(duration) * 1000.0).round() / 1000.0
+}
+
+/// Times the parse step and the parts of a day binary, printing each answer in the selected format
+pub struct Report {
+    name: &'static str,
+    year: u16,
+    /// Whether the answers are those of the real input, see [`InputArgs::is_real`]
+    real: bool,
+    day: u8,
+    format: Format,
+    visualization: Option<String>,
+    parse: Duration,
+}
+
+impl Report {
+    pub fn new(files: &DayFiles, args: &InputArgs) -> Self {
+        Report {
+            name: files.name,
+            year: files.year,
+            real: args.is_real(),
+            day: files.day,
+            format: args.format,
+            visualization: args.visualization.clone(),
+            parse: Duration::ZERO,
+        }
+    }
+
+    /// Times the parse step
+    pub fn parse<T, E>(&mut self, parse: impl FnOnce() -> Result<T, E>) -> Result<T, E> {
+        let _span = tracing::info_span!("parse", year = self.year, day = self.day).entered();
+        let start = Instant::now();
+        let input = parse()?;
+        self.parse = start.elapsed();
+        Ok(input)
+    }
+
+    /// Times a part and prints its answer, prefixed by 'label' in text mode
+    pub fn part<T: Display>(&self, part: u8, label: &str, solve: impl FnOnce() -> T) -> T {
+        let _span = tracing::info_span!("part", year = self.year, day = self.day, part).entered();
+        let start = Instant::now();
+        let answer = solve();
+        self.print(part, label, &answer, start.elapsed());
+        answer
+    }
+
+    /// Times a part that can fail and prints its answer, see [`Report::part`]
+    pub fn try_part<T: Display, E>(
+        &self,
+        part: u8,
+        label: &str,
+        solve: impl FnOnce() -> Result<T, E>,
+    ) -> Result<T, E> {
+        let _span = tracing::info_span!("part", year = self.year, day = self.day, part).entered();
+        let start = Instant::now();
+        let answer = solve()?;
+        self.print(part, label, &answer, start.elapsed());
+        Ok(answer)
+    }
+
+    /// Prints the answer, and warns on stderr if the ledger rejects the answer of the real input
+    fn print(&self, part: u8, label: &str, answer: &impl Display, solve: Duration) {
+        let answer = answer.to_string();
+        if self.real {
+            ledger::warn(self.name, part, &answer);
+        }
+        match self.format {
+            Format::Text => println!("{}: {}", label, answer),
+            Format::Json => {
+                let record = Record {
+                    year: self.year,
+                    day: self.day,
+                    part,
+                    answer,
+                    parse: self.parse,
+                    solve,
+                    parse_allocations: None,
+                    solve_allocations: None,
+                };
+                println!("{}", record.to_json());
+            }
+        }
+    }
+
+    /// Where visualizations are written: stdout in text mode, and in JSON mode
+    /// the file given with `--visualization`, or stderr, to keep stdout parseable
+    pub fn visualization(&self) -> io::Result<Box<dyn Write>> {
+        match (self.format, &self.visualization) {
+            (_, Some(path)) => Ok(Box::new(File::create(path)?)),
+            (Format::Text, None) => Ok(Box::new(io::stdout())),
+            (Format::Json, None) => Ok(Box::new(io::stderr())),
+        }
+    }
+}
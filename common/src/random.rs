@@ -0,0 +1,85 @@
+use std::ops::RangeInclusive;
+
+/// Small seeded random number generator used to generate puzzle inputs.
+///
+/// Implements SplitMix64, so that a seed always produces the same input, whatever the platform.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..n`
+    ///
+    /// Panics if 'n' is zero
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Cannot pick a value below zero");
+        // Widening multiply, the bias is negligible for the sizes of the puzzles
+        ((u128::from(self.next_u64()) * n as u128) >> 64) as usize
+    }
+
+    /// Uniform value in 'range'
+    ///
+    /// Panics if the range is empty
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Cannot pick a value in an empty range");
+        let span = (end - start) as u64 + 1;
+        start + ((u128::from(self.next_u64()) * span as u128) >> 64) as i64
+    }
+
+    /// True with probability 'p'
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    /// Uniformly picked item of 'items'
+    ///
+    /// Panics if 'items' is empty
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Shuffles 'items' in place
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_values() {
+        let (mut a, mut b) = (Rng::new(7), Rng::new(7));
+        assert!((0..100).all(|_| a.next_u64() == b.next_u64()));
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+    }
+
+    #[test]
+    fn stays_in_range() {
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!(rng.below(3) < 3);
+            assert!((-2..=2).contains(&rng.range(-2..=2)));
+        }
+        assert_eq!(rng.range(5..=5), 5);
+        assert!((0..1000).any(|_| rng.range(-2..=2) == -2));
+        assert!((0..1000).any(|_| rng.range(-2..=2) == 2));
+    }
+}
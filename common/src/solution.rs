@@ -0,0 +1,185 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::io::BufRead;
+
+use crate::error;
+use crate::input::{DayFiles, InputArgs};
+
+/// Solution of a day, through which the runner, the benchmarks, the tests and the server solve every
+/// day the same way.
+///
+/// The value holds the parameters of the day, e.g. its `Config`, and [`Default`] gives those of the
+/// real puzzle.
+pub trait Solution: Default + 'static {
+    /// Parsed puzzle input
+    type Input: 'static;
+    /// Answer of both parts
+    type Answer: Display;
+
+    /// Input files of the day
+    const FILES: &'static DayFiles;
+
+    /// Solution with the parameters selected by 'args', see [`crate::config::load`]. Days without
+    /// parameters keep the default.
+    fn new(_args: &InputArgs) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::default())
+    }
+
+    /// Every problem found in the structure of an input
+    fn check(&self, reader: impl BufRead) -> Vec<error::Error>;
+
+    fn parse(&self, reader: impl BufRead) -> error::Result<Self::Input>;
+
+    fn part_one(&self, input: &Self::Input) -> error::Result<Self::Answer>;
+
+    fn part_two(&self, input: &Self::Input) -> error::Result<Self::Answer>;
+}
+
+/// [`Solution`] whose input and answer types are hidden, so that different days can be listed
+/// together, see [`Entry`]
+pub trait AnySolution {
+    /// Every problem found in the structure of an input
+    fn check(&self, reader: &mut dyn BufRead) -> Vec<error::Error>;
+
+    fn parse(&self, reader: &mut dyn BufRead) -> error::Result<Box<dyn Parsed + '_>>;
+}
+
+/// Input parsed by an [`AnySolution`], answering its parts as strings
+pub trait Parsed {
+    fn part_one(&self) -> error::Result<String>;
+
+    fn part_two(&self) -> error::Result<String>;
+}
+
+/// Solution behind an [`AnySolution`]
+struct Erased<S>(S);
+
+impl<S: Solution> AnySolution for Erased<S> {
+    fn check(&self, reader: &mut dyn BufRead) -> Vec<error::Error> {
+        self.0.check(reader)
+    }
+
+    fn parse(&self, reader: &mut dyn BufRead) -> error::Result<Box<dyn Parsed + '_>> {
+        let input = self.0.parse(reader)?;
+        Ok(Box::new(Bound {
+            solution: &self.0,
+            input,
+        }))
+    }
+}
+
+/// Parsed input along with the solution that parsed it
+struct Bound<'a, S: Solution> {
+    solution: &'a S,
+    input: S::Input,
+}
+
+impl<S: Solution> Parsed for Bound<'_, S> {
+    fn part_one(&self) -> error::Result<String> {
+        let answer = self.solution.part_one(&self.input)?;
+        Ok(answer.to_string())
+    }
+
+    fn part_two(&self) -> error::Result<String> {
+        let answer = self.solution.part_two(&self.input)?;
+        Ok(answer.to_string())
+    }
+}
+
+/// Builds the solution of a day with the parameters selected by the arguments
+type Loader = fn(&InputArgs) -> Result<Box<dyn AnySolution>, Box<dyn Error>>;
+
+/// Day listed by a registry: its files, and how to build its solution
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub files: &'static DayFiles,
+    load: Loader,
+}
+
+impl Entry {
+    /// Entry of the day solved by 'S'
+    pub const fn of<S: Solution>() -> Self {
+        Entry {
+            files: S::FILES,
+            load: load::<S>,
+        }
+    }
+
+    /// Solution of the day with the parameters selected by 'args', see [`Solution::new`]
+    pub fn load(&self, args: &InputArgs) -> Result<Box<dyn AnySolution>, Box<dyn Error>> {
+        (self.load)(args)
+    }
+}
+
+fn load<S: Solution>(args: &InputArgs) -> Result<Box<dyn AnySolution>, Box<dyn Error>> {
+    Ok(Box::new(Erased(S::new(args)?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    const FILES: DayFiles = crate::day_files!(2015, 1, "example.txt");
+
+    /// Sum and product of the numbers, the product is scaled by the number of `--set` options
+    #[derive(Default)]
+    struct Numbers {
+        factor: i64,
+    }
+
+    impl Solution for Numbers {
+        type Input = Vec<i64>;
+        type Answer = i64;
+
+        const FILES: &'static DayFiles = &FILES;
+
+        fn new(args: &InputArgs) -> Result<Self, Box<dyn std::error::Error>> {
+            Ok(Numbers {
+                factor: args.set.len() as i64 + 1,
+            })
+        }
+
+        fn check(&self, reader: impl BufRead) -> Vec<Error> {
+            self.parse(reader).err().into_iter().collect()
+        }
+
+        fn parse(&self, reader: impl BufRead) -> error::Result<Vec<i64>> {
+            let mut numbers = Vec::new();
+            for line in error::lines(FILES.name, reader) {
+                let line = line?;
+                for token in line.text.split_whitespace() {
+                    numbers.push(line.parse(token)?);
+                }
+            }
+            Ok(numbers)
+        }
+
+        fn part_one(&self, input: &Vec<i64>) -> error::Result<i64> {
+            Ok(input.iter().sum())
+        }
+
+        fn part_two(&self, input: &Vec<i64>) -> error::Result<i64> {
+            Ok(self.factor * input.iter().product::<i64>())
+        }
+    }
+
+    #[test]
+    fn solves_through_the_entry() {
+        let entry = Entry::of::<Numbers>();
+        assert_eq!((entry.files.year, entry.files.day), (2015, 1));
+
+        let args = InputArgs {
+            set: vec!["factor=2".to_string()],
+            ..Default::default()
+        };
+        let solution = entry.load(&args).unwrap();
+        let parsed = solution.parse(&mut "2 3 4\n".as_bytes()).unwrap();
+        assert_eq!(parsed.part_one().unwrap(), "9");
+        assert_eq!(parsed.part_two().unwrap(), "48");
+
+        assert!(solution.check(&mut "2 3 4\n".as_bytes()).is_empty());
+        assert_eq!(solution.check(&mut "2 x\n".as_bytes()).len(), 1);
+        assert!(solution.parse(&mut "2 x\n".as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,20 @@
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::EnvFilter;
+
+/// Prints the spans and events of the solutions to stderr, as selected by the `RUST_LOG` environment
+/// variable, e.g. `RUST_LOG=info` for the time of every parse and part, or
+/// `RUST_LOG=y2024_day09=debug` for the decisions of a single day.
+///
+/// Nothing is printed when `RUST_LOG` is not set, and stdout is left to the answers.
+pub fn init() {
+    let filter = EnvFilter::builder()
+        .with_default_directive(LevelFilter::OFF.into())
+        .from_env_lossy();
+    // Fails only if a subscriber is already installed, which then keeps receiving the events
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(std::io::stderr)
+        .try_init();
+}
@@ -0,0 +1,19 @@
+#![no_main]
+
+use common::solution::Solution;
+use libfuzzer_sys::fuzz_target;
+use y2024_day01::Solver;
+
+// Malformed inputs must be reported as errors, never panic, and the inputs the runner accepts must
+// be solved or rejected with an error
+fuzz_target!(|input: &[u8]| {
+    let solver = Solver::default();
+    let parsed = solver.parse(input);
+    if !solver.check(input).is_empty() {
+        return;
+    }
+    if let Ok(parsed) = parsed {
+        let _ = solver.part_one(&parsed);
+        let _ = solver.part_two(&parsed);
+    }
+});
@@ -0,0 +1,376 @@
+/*
+    Two dimensional grid shared by the solutions working on maps
+*/
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+/// Position of a cell as (row, column)
+pub type Position = (usize, usize);
+
+/// Offsets of the four orthogonal neighbours: up, right, down, left
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets of the eight neighbours, clockwise starting from up
+pub const ALL_DIRECTIONS: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// Error returned when the text of a grid has rows of different lengths
+#[derive(Debug, PartialEq, Eq)]
+pub struct RaggedRowError {
+    pub row: usize,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl Display for RaggedRowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Row {} has {} cells, expected {}",
+            self.row + 1,
+            self.found,
+            self.expected
+        )
+    }
+}
+
+impl Error for RaggedRowError {}
+
+/// Rectangular grid of cells stored row by row in a single vector
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells listed row by row
+    ///
+    /// Panics if the number of cells does not match the dimensions
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "Grid size mismatch");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid with every cell set to 'value'
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses one cell per character, one row per line, mapping each character with 'cell'
+    pub fn parse(text: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, RaggedRowError> {
+        Grid::parse_lines(text.lines(), |_, c| cell(c))
+    }
+
+    /// Parses one cell per character of each line, 'cell' also receives the position of the character
+    pub fn parse_lines<S: AsRef<str>>(
+        lines: impl IntoIterator<Item = S>,
+        mut cell: impl FnMut(Position, char) -> T,
+    ) -> Result<Self, RaggedRowError> {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+
+        for (row, line) in lines.into_iter().enumerate() {
+            let start = cells.len();
+            cells.extend(
+                line.as_ref()
+                    .chars()
+                    .enumerate()
+                    .map(|(col, c)| cell((row, col), c)),
+            );
+
+            let found = cells.len() - start;
+            if row == 0 {
+                width = found;
+            } else if found != width {
+                return Err(RaggedRowError {
+                    row,
+                    expected: width,
+                    found,
+                });
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Checks whether the position lies inside the grid
+    pub fn contains(&self, (row, col): Position) -> bool {
+        row < self.height && col < self.width
+    }
+
+    /// Returns the cell at the position, if inside the grid
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|i| &self.cells[i])
+    }
+
+    /// Returns the cell at the position mutably, if inside the grid
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|i| &mut self.cells[i])
+    }
+
+    /// Moves the position by the offset, returning None when leaving the grid
+    pub fn step(&self, (row, col): Position, (d_row, d_col): (isize, isize)) -> Option<Position> {
+        let position = (
+            row.checked_add_signed(d_row)?,
+            col.checked_add_signed(d_col)?,
+        );
+        self.contains(position).then_some(position)
+    }
+
+    /// Iterates over the positions of the orthogonal neighbours inside the grid
+    pub fn neighbours(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&offset| self.step(position, offset))
+    }
+
+    /// Iterates over the positions of the orthogonal and diagonal neighbours inside the grid
+    pub fn all_neighbours(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ALL_DIRECTIONS
+            .iter()
+            .filter_map(move |&offset| self.step(position, offset))
+    }
+
+    /// Iterates over every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i / width, i % width))
+    }
+
+    /// Iterates over every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Returns the position of the first cell matching the predicate
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// Iterates over the rows, from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() rejects a zero size, an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterates over the columns, from left to right
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| (0..self.height).map(move |row| &self[(row, col)]))
+    }
+
+    /// Iterates over the diagonals going down and to the right, starting from the bottom left corner
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        self.lines_from_edges((1, 1))
+    }
+
+    /// Iterates over the diagonals going down and to the left, starting from the top left corner
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        self.lines_from_edges((1, -1))
+    }
+
+    /// Maps every cell, keeping the layout
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.0 * self.width + position.1)
+    }
+
+    /// Walks along 'direction' from every cell of the top row and of the side column it goes away from
+    fn lines_from_edges(
+        &self,
+        direction: (isize, isize),
+    ) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let top = (0..self.width).map(|col| (0, col));
+        let starts: Vec<Position> = if direction.1 > 0 {
+            (1..self.height)
+                .rev()
+                .map(|row| (row, 0))
+                .chain(top)
+                .collect()
+        } else {
+            let last_col = self.width.saturating_sub(1);
+            top.chain((1..self.height).map(|row| (row, last_col)))
+                .collect()
+        };
+
+        starts
+            .into_iter()
+            .filter(|&position| self.contains(position))
+            .map(move |start| {
+                std::iter::successors(Some(start), move |&position| self.step(position, direction))
+                    .map(move |position| &self[position])
+            })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        match self.index_of(position) {
+            Some(i) => &self.cells[i],
+            None => panic!(
+                "Position {:?} outside of {}x{} grid",
+                position, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        match self.index_of(position) {
+            Some(i) => &mut self.cells[i],
+            None => panic!(
+                "Position {:?} outside of {}x{} grid",
+                position, self.width, self.height
+            ),
+        }
+    }
+}
+
+/// Draws one line per row with the cells side by side
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters() -> Grid<char> {
+        Grid::parse("abc\ndef", |c| c).unwrap()
+    }
+
+    fn strings<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn walks_rows_columns_and_diagonals() {
+        let grid = letters();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(
+            grid.rows()
+                .map(|row| row.iter().collect())
+                .collect::<Vec<String>>(),
+            ["abc", "def"]
+        );
+        assert_eq!(strings(grid.columns()), ["ad", "be", "cf"]);
+        assert_eq!(strings(grid.diagonals()), ["d", "ae", "bf", "c"]);
+        assert_eq!(strings(grid.anti_diagonals()), ["a", "bd", "ce", "f"]);
+
+        let empty = Grid::parse("", |c| c).unwrap();
+        assert_eq!(empty.diagonals().count(), 0);
+        assert_eq!(empty.anti_diagonals().count(), 0);
+    }
+
+    #[test]
+    fn steps_stay_inside_the_grid() {
+        let grid = letters();
+        assert_eq!(grid.step((0, 0), (-1, 0)), None);
+        assert_eq!(grid.step((0, 0), (0, -1)), None);
+        assert_eq!(grid.step((1, 2), (0, 1)), None);
+        assert_eq!(grid.step((1, 2), (1, 0)), None);
+        assert_eq!(grid.step((1, 2), (-1, -1)), Some((0, 1)));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((1, 1)), Some(&'e'));
+    }
+
+    #[test]
+    fn neighbours_at_the_edges() {
+        let grid = letters();
+        assert_eq!(
+            grid.neighbours((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours((1, 1)).collect::<Vec<_>>(),
+            [(0, 1), (1, 2), (1, 0)]
+        );
+        assert_eq!(
+            grid.all_neighbours((0, 1)).collect::<Vec<_>>(),
+            [(0, 2), (1, 2), (1, 1), (1, 0), (0, 0)]
+        );
+
+        let single = Grid::filled(1, 1, '.');
+        assert_eq!(single.neighbours((0, 0)).count(), 0);
+        assert_eq!(single.all_neighbours((0, 0)).count(), 0);
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let error = Grid::parse_lines(["ab", "c", "de"], |_, c| c).unwrap_err();
+        assert_eq!(
+            error,
+            RaggedRowError {
+                row: 1,
+                expected: 2,
+                found: 1
+            }
+        );
+        assert_eq!(error.to_string(), "Row 2 has 1 cells, expected 2");
+
+        let mut seen = Vec::new();
+        Grid::parse_lines(["ab", "cd"], |position, c| seen.push((position, c))).unwrap();
+        assert_eq!(
+            seen,
+            [((0, 0), 'a'), ((0, 1), 'b'), ((1, 0), 'c'), ((1, 1), 'd')]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Position (2, 0) outside of 3x2 grid")]
+    fn index_outside_panics() {
+        let _ = letters()[(2, 0)];
+    }
+}
@@ -0,0 +1,77 @@
+//! Every implemented day, as the [`Entry`] of its [`Solution`](common::solution::Solution), so that
+//! the runner, the benchmarks, the tests and the server go through all of them the same way.
+//!
+//! A new day is listed by adding its crate to the dependencies and its `Solver` to the days of its
+//! year.
+
+use common::solution::Entry;
+
+/// Years with an implemented solution, along with their days in order
+pub static YEARS: [(u16, &[Entry]); 1] = [(2024, &Y2024)];
+
+static Y2024: [Entry; 15] = [
+    Entry::of::<y2024_day01::Solver>(),
+    Entry::of::<y2024_day02::Solver>(),
+    Entry::of::<y2024_day03::Solver>(),
+    Entry::of::<y2024_day04::Solver>(),
+    Entry::of::<y2024_day05::Solver>(),
+    Entry::of::<y2024_day06::Solver>(),
+    Entry::of::<y2024_day07::Solver>(),
+    Entry::of::<y2024_day08::Solver>(),
+    Entry::of::<y2024_day09::Solver>(),
+    Entry::of::<y2024_day10::Solver>(),
+    Entry::of::<y2024_day11::Solver>(),
+    Entry::of::<y2024_day12::Solver>(),
+    Entry::of::<y2024_day13::Solver>(),
+    Entry::of::<y2024_day14::Solver>(),
+    Entry::of::<y2024_day15::Solver>(),
+];
+
+/// Days of 'year', or of every year, in order
+pub fn entries(year: Option<u16>) -> impl Iterator<Item = &'static Entry> {
+    YEARS
+        .iter()
+        .filter(move |(y, _)| year.is_none_or(|year| year == *y))
+        .flat_map(|(_, days)| days.iter())
+}
+
+/// Day 'day' of 'year', if implemented
+pub fn get(year: u16, day: u8) -> Option<&'static Entry> {
+    entries(Some(year)).find(|entry| entry.files.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::Answers;
+    use common::input::{self, InputArgs};
+
+    #[test]
+    fn lists_every_day_once() {
+        for (year, days) in &YEARS {
+            assert!(days.iter().all(|entry| entry.files.year == *year));
+            assert!(days.windows(2).all(|w| w[0].files.day < w[1].files.day));
+            for entry in days.iter() {
+                let name = format!("y{}_day{:02}", year, entry.files.day);
+                assert_eq!(entry.files.name, name);
+            }
+        }
+        assert_eq!(get(2024, 7).unwrap().files.name, "y2024_day07");
+        assert!(get(2024, 25).is_none());
+        assert!(get(2014, 1).is_none());
+        assert_eq!(entries(None).count(), entries(Some(2024)).count());
+    }
+
+    #[test]
+    #[ignore = "needs the real inputs and the answers file"]
+    fn solves_every_real_input() {
+        for entry in entries(None) {
+            let answers = Answers::load(entry.files).unwrap();
+            let solution = entry.load(&InputArgs::default()).unwrap();
+            let mut reader = input::real(entry.files).unwrap();
+            let parsed = solution.parse(&mut reader).unwrap();
+            answers.check(1, parsed.part_one().unwrap());
+            answers.check(2, parsed.part_two().unwrap());
+        }
+    }
+}
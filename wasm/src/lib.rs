@@ -0,0 +1,103 @@
+//! Solutions of every day compiled to WebAssembly, taking the puzzle input as a string.
+//!
+//! Built for `wasm32-unknown-unknown` and bound with `wasm-bindgen`, see `www/index.html`. The
+//! puzzle parameters are always the default ones, as there is no `aoc.toml` in the browser.
+
+use common::input::InputArgs;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Years with an implemented solution
+#[wasm_bindgen]
+pub fn years() -> Vec<u16> {
+    registry::YEARS.iter().map(|(year, _)| *year).collect()
+}
+
+/// Days of 'year' with an implemented solution
+#[wasm_bindgen]
+pub fn days(year: u16) -> Vec<u8> {
+    registry::entries(Some(year))
+        .map(|entry| entry.files.day)
+        .collect()
+}
+
+/// Answer of the first part of 'day' of 'year' for 'input'
+#[wasm_bindgen]
+pub fn part_one(year: u16, day: u8, input: &str) -> Result<String, String> {
+    solve(year, day, 1, input)
+}
+
+/// Answer of the second part of 'day' of 'year' for 'input'
+#[wasm_bindgen]
+pub fn part_two(year: u16, day: u8, input: &str) -> Result<String, String> {
+    solve(year, day, 2, input)
+}
+
+/// Answer of 'part' of 'day' of 'year' for 'input', or the reason it cannot be computed.
+///
+/// Nothing is timed, `std::time::Instant` is not available on `wasm32-unknown-unknown`.
+pub fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<String, String> {
+    let Some(entry) = registry::get(year, day) else {
+        if registry::entries(Some(year)).next().is_none() {
+            return Err(format!("Year {} is not implemented", year));
+        }
+        return Err(format!("Day {} of {} is not implemented", day, year));
+    };
+    if !(1..=2).contains(&part) {
+        return Err(format!("Part {} does not exist", part));
+    }
+    let solution = entry
+        .load(&InputArgs::default())
+        .map_err(|e| e.to_string())?;
+
+    // Malformed inputs are reported before solving, rather than as wrong answers or panics
+    let problems = solution.check(&mut input.as_bytes());
+    if let Some(first) = problems.first() {
+        return Err(match problems.len() {
+            1 => first.to_string(),
+            n => format!("{} (and {} more problems)", first, n - 1),
+        });
+    }
+
+    let parsed = solution
+        .parse(&mut input.as_bytes())
+        .map_err(|e| e.to_string())?;
+    let answer = match part {
+        1 => parsed.part_one(),
+        _ => parsed.part_two(),
+    };
+    answer.map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves_from_a_string() {
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        assert_eq!(part_one(2024, 1, input).unwrap(), "11");
+        assert_eq!(part_two(2024, 1, input).unwrap(), "31");
+    }
+
+    #[test]
+    fn reports_problems() {
+        assert_eq!(
+            part_one(2024, 16, "").unwrap_err(),
+            "Day 16 of 2024 is not implemented"
+        );
+        assert_eq!(
+            part_one(2014, 1, "").unwrap_err(),
+            "Year 2014 is not implemented"
+        );
+        assert_eq!(
+            solve(2024, 1, 3, "1   2\n").unwrap_err(),
+            "Part 3 does not exist"
+        );
+        let error = part_one(2024, 1, "3   4\nx   3\n2\n").unwrap_err();
+        assert!(
+            error.contains("line 2") && error.ends_with("(and 1 more problems)"),
+            "{}",
+            error
+        );
+    }
+}
@@ -0,0 +1,15 @@
+// Solves off the main thread, so that a long part (or day fourteen without a tree) never freezes the page
+import init, { part_one, part_two } from "./pkg/aoc_wasm.js";
+
+const ready = init();
+
+self.onmessage = async ({ data: { year, day, input } }) => {
+  await ready;
+  for (const [part, solve] of [[1, part_one], [2, part_two]]) {
+    try {
+      self.postMessage({ part, answer: solve(year, day, input) });
+    } catch (error) {
+      self.postMessage({ part, error: String(error) });
+    }
+  }
+};
@@ -0,0 +1,28 @@
+use common::random::Rng;
+
+/// Two lists of 'lines' five-digit location IDs, drawn from a shared pool so that IDs repeat
+pub fn input(seed: u64, lines: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let pool: Vec<i64> = (0..lines.max(1))
+        .map(|_| rng.range(10000..=99999))
+        .collect();
+
+    (0..lines)
+        .map(|_| format!("{}   {}\n", rng.choose(&pool), rng.choose(&pool)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part_one, part_two};
+
+    #[test]
+    fn parses_and_solves() {
+        let lists = parse(input(1, 500).as_bytes()).unwrap();
+        assert_eq!(lists.vec_a.len(), 500);
+        part_one(&lists);
+        assert!(part_two(&lists) > 0);
+        assert_eq!(input(1, 500), input(1, 500));
+    }
+}
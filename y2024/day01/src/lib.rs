@@ -0,0 +1,141 @@
+/*
+    You can find the challenge description here: https://adventofcode.com/2024/day/1
+*/
+use std::io::BufRead;
+
+use common::error::{self, Error, Result};
+use common::input::DayFiles;
+use common::lint;
+use common::solution::Solution;
+
+pub mod generate;
+
+/// Puzzle input files of the day
+pub const FILES: DayFiles = common::day_files!(2024, 1, "input_test.txt");
+
+/// The two location lists, both sorted in ascending order
+pub struct Lists {
+    pub vec_a: Vec<i32>,
+    pub vec_b: Vec<i32>,
+}
+
+/// Parses the two location lists and sorts them
+pub fn parse(reader: impl BufRead)-> Result<Lists>{
+    let (mut vec_a, mut vec_b) = (Vec::new(), Vec::new());
+
+    for line in error::lines(FILES.name, reader){
+        let line = line?;
+        let mut values = line.text.split_whitespace();
+        let value_a = values.next().ok_or_else(|| line.missing("location ID"))?;
+        let value_b = values.next().ok_or_else(|| line.missing("second location ID"))?;
+        if let Some(extra) = values.next() {
+            return Err(line.error(extra, "expected two location IDs"));
+        }
+        vec_a.push(line.parse::<i32>(value_a)?);
+        vec_b.push(line.parse::<i32>(value_b)?);
+    }
+
+    vec_a.sort(); vec_b.sort();
+    Ok(Lists { vec_a, vec_b })
+}
+
+/// Checks that every line holds two location IDs, reporting every problem found
+pub fn check(reader: impl BufRead) -> Vec<Error> {
+    lint::check(FILES.name, reader, |lines| lint::each_line(lines, |text| parse(text)))
+}
+
+/// Solves part one pairing up the sorted input vectors
+pub fn part_one(lists: &Lists)->i32{
+    lists.vec_a.iter().zip(lists.vec_b.iter()).map(|(el_a, el_b)| (el_a-el_b).abs()).sum()
+}
+
+/// Solves part two in a single pass over the sorted input vectors
+pub fn part_two(lists: &Lists)->i128{
+    let (vec_a, vec_b) = (&lists.vec_a, &lists.vec_b);
+    let mut result = 0i128;
+    let mut j=0;
+    let mut count = 0;
+    for (i, &a) in vec_a.iter().enumerate(){
+        // Repeated values of the first list reuse the count of their first occurrence
+        if i == 0 || vec_a[i-1] != a {
+            count = 0;
+            while j<vec_b.len() && vec_b[j] <= a {
+                if a == vec_b[j] {
+                    count+=1;
+                }
+                j+=1; 
+            };
+        }
+        result += i128::from(a) * count;
+    }
+    result
+}
+
+/// Distance between the two location lists, then their similarity score
+#[derive(Default)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Lists;
+    type Answer = i128;
+
+    const FILES: &'static DayFiles = &FILES;
+
+    fn check(&self, reader: impl BufRead) -> Vec<Error> {
+        check(reader)
+    }
+
+    fn parse(&self, reader: impl BufRead) -> Result<Lists> {
+        parse(reader)
+    }
+
+    fn part_one(&self, input: &Lists) -> Result<i128> {
+        Ok(part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Lists) -> Result<i128> {
+        Ok(part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::Answers;
+    use common::input;
+    use proptest::prelude::*;
+
+    #[test]
+    fn example() {
+        let lists = parse(input::example(&FILES).unwrap()).unwrap();
+        assert_eq!(part_one(&lists), 11);
+        assert_eq!(part_two(&lists), 31);
+    }
+
+    #[test]
+    #[ignore = "needs the real input and the answers file"]
+    fn real_input() {
+        let answers = Answers::load(&FILES).unwrap();
+        let lists = parse(input::real(&FILES).unwrap()).unwrap();
+        answers.check(1, part_one(&lists));
+        answers.check(2, part_two(&lists));
+    }
+
+    proptest! {
+        #[test]
+        fn part_two_matches_naive(
+            mut vec_a in prop::collection::vec(0..20i32, 0..50),
+            mut vec_b in prop::collection::vec(0..20i32, 0..50),
+        ) {
+            // Similarity score: every value of the left list times its count in the right list
+            let naive: i128 = vec_a
+                .iter()
+                .map(|&a| i128::from(a) * vec_b.iter().filter(|&&b| b == a).count() as i128)
+                .sum();
+
+            vec_a.sort();
+            vec_b.sort();
+            prop_assert_eq!(part_two(&Lists { vec_a, vec_b }), naive);
+        }
+    }
+}
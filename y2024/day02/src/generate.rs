@@ -0,0 +1,45 @@
+use common::random::Rng;
+
+/// 'reports' reports of 'levels' levels each, mostly safe with some bad levels thrown in
+///
+/// Panics if 'levels' is below two, the parser rejects such reports
+pub fn input(seed: u64, reports: usize, levels: usize) -> String {
+    assert!(levels >= 2, "A report needs at least two levels");
+    let mut rng = Rng::new(seed);
+    let mut text = String::new();
+
+    for _ in 0..reports {
+        let direction = if rng.chance(0.5) { 1 } else { -1 };
+        let mut level = rng.range(10..=90);
+        let mut report = vec![level];
+        for _ in 1..levels {
+            let step = if rng.chance(0.1) {
+                // Unsafe step: flat, too steep, or going the wrong way
+                *rng.choose(&[0, 4, 5, -1, -2])
+            } else {
+                rng.range(1..=3)
+            };
+            level += direction * step;
+            report.push(level);
+        }
+
+        let report: Vec<String> = report.iter().map(i64::to_string).collect();
+        text.push_str(&report.join(" "));
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part_one, part_two};
+
+    #[test]
+    fn parses_and_solves() {
+        let reports = parse(input(2, 1000, 6).as_bytes()).unwrap();
+        assert_eq!(reports.len(), 1000);
+        let (safe, dampened) = (part_one(&reports), part_two(&reports));
+        assert!(0 < safe && safe < dampened && dampened < 1000);
+    }
+}
@@ -0,0 +1,227 @@
+/*
+    You can find the challenge description here: https://adventofcode.com/2024/day/2
+*/
+
+use std::io::BufRead;
+
+use common::error::{self, Error, Result};
+use common::input::DayFiles;
+use common::lint;
+use common::solution::Solution;
+
+pub mod generate;
+
+/// Puzzle input files of the day
+pub const FILES: DayFiles = common::day_files!(2024, 2, "test.txt");
+
+/// Parses the reports, one list of levels per line
+pub fn parse(
+    reader: impl BufRead,
+) -> Result<Vec<Vec<i32>>> {
+    let mut reports = Vec::new();
+
+    for line in error::lines(FILES.name, reader) {
+        let line = line?;
+        let report = line
+            .text
+            .split_whitespace()
+            .map(|el| line.parse::<i32>(el))
+            .collect::<Result<Vec<i32>>>()?;
+
+        // A report needs two levels to tell whether it is increasing or decreasing
+        if report.len() < 2 {
+            return Err(line.missing("level, expected at least two"));
+        }
+        reports.push(report);
+    }
+
+    Ok(reports)
+}
+
+/// Checks that every report holds at least two levels, reporting every problem found
+pub fn check(reader: impl BufRead) -> Vec<Error> {
+    lint::check(FILES.name, reader, |lines| lint::each_line(lines, |text| parse(text)))
+}
+
+/// Check if input reports are valid according to rules
+fn is_valid_report(report: &[i32], report_right: Option<&[i32]>) -> bool {
+    let mut last_num = report[0];
+
+    // The level following report[0] may be the first one of the right slice
+    let second = report
+        .get(1)
+        .or_else(|| report_right.and_then(|report| report.first()));
+    let ascending = match second {
+        Some(second) => *second > report[0],
+        // A single level is always safe
+        None => return true,
+    };
+
+    for num in &report[1..] {
+        if ascending && ((num - last_num) < 1 || (num - last_num) > 3) {
+            return false;
+        }
+        if !ascending && ((last_num - num) < 1 || (last_num - num) > 3) {
+            return false;
+        }
+        last_num = *num;
+    }
+
+    if let Some(report) = report_right {
+        for num in report {
+            if ascending && ((num - last_num) < 1 || (num - last_num) > 3) {
+                return false;
+            }
+            if !ascending && ((last_num - num) < 1 || (last_num - num) > 3) {
+                return false;
+            }
+            last_num = *num;
+        }
+    }
+
+    true
+}
+
+pub fn part_one(reports: &[Vec<i32>]) -> i32 {
+    // Count number of valid reports
+    reports
+        .iter()
+        .map(|report| is_valid_report(report, None) as i32)
+        .sum()
+}
+
+pub fn part_two(reports: &[Vec<i32>]) -> i32 {
+    let mut safe_levels = 0;
+
+    for report in reports {
+        // Try without removing anything
+        if is_valid_report(report, None) {
+            safe_levels += 1;
+            continue;
+        }
+
+        let len = report.len();
+
+        // Try removing first and last element
+        if is_valid_report(&report[1..], None) || is_valid_report(&report[..len - 1], None) {
+            safe_levels += 1;
+            continue;
+        }
+
+        // Try removing elements in between
+        for i in 1..len - 1 {
+            if is_valid_report(&report[0..i], Some(&report[i + 1..])) {
+                safe_levels += 1;
+                break;
+            }
+        }
+    }
+    safe_levels
+}
+
+/// Safe reports, first as they are and then with one bad level tolerated
+#[derive(Default)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<Vec<i32>>;
+    type Answer = i32;
+
+    const FILES: &'static DayFiles = &FILES;
+
+    fn check(&self, reader: impl BufRead) -> Vec<Error> {
+        check(reader)
+    }
+
+    fn parse(&self, reader: impl BufRead) -> Result<Vec<Vec<i32>>> {
+        parse(reader)
+    }
+
+    fn part_one(&self, input: &Vec<Vec<i32>>) -> Result<i32> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Vec<Vec<i32>>) -> Result<i32> {
+        Ok(part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::Answers;
+    use common::input;
+    use proptest::prelude::*;
+
+    #[test]
+    fn example() {
+        let reports = parse(input::example(&FILES).unwrap()).unwrap();
+        assert_eq!(part_one(&reports), 2);
+        assert_eq!(part_two(&reports), 4);
+    }
+
+    #[test]
+    fn check_agrees_with_parse() {
+        let input = "7 6 4 2 1\n\n1 3 6 7 9\n";
+        let error = parse(input.as_bytes()).err().unwrap();
+        let problems = check(input.as_bytes());
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].to_string(), error.to_string());
+    }
+
+    #[test]
+    #[ignore = "needs the real input and the answers file"]
+    fn real_input() {
+        let answers = Answers::load(&FILES).unwrap();
+        let reports = parse(input::real(&FILES).unwrap()).unwrap();
+        answers.check(1, part_one(&reports));
+        answers.check(2, part_two(&reports));
+    }
+
+    /// Reference check: the levels all increase, or all decrease, by one to three
+    fn naive_is_safe(report: &[i32]) -> bool {
+        let diffs: Vec<i32> = report.windows(2).map(|w| w[1] - w[0]).collect();
+        diffs.iter().all(|d| (1..=3).contains(d)) || diffs.iter().all(|d| (-3..=-1).contains(d))
+    }
+
+    /// Reports of at least two levels, mostly moving by small steps so that safe ones are common
+    fn report() -> impl Strategy<Value = Vec<i32>> {
+        (0..20i32, prop::collection::vec(-4..=4i32, 1..8)).prop_map(|(start, steps)| {
+            std::iter::once(start)
+                .chain(steps.iter().scan(start, |level, step| {
+                    *level += step;
+                    Some(*level)
+                }))
+                .collect()
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn is_valid_report_matches_naive(report in report()) {
+            prop_assert_eq!(is_valid_report(&report, None), naive_is_safe(&report));
+        }
+
+        #[test]
+        fn is_valid_report_joins_both_slices(report in report(), split in any::<prop::sample::Index>()) {
+            let split = 1 + split.index(report.len() - 1);
+            let (left, right) = report.split_at(split);
+            prop_assert_eq!(is_valid_report(left, Some(right)), naive_is_safe(&report));
+        }
+
+        #[test]
+        fn part_two_matches_naive(reports in prop::collection::vec(report(), 1..10)) {
+            let naive = reports
+                .iter()
+                .filter(|report| {
+                    (0..report.len()).any(|skip| {
+                        let mut dampened = report.to_vec();
+                        dampened.remove(skip);
+                        naive_is_safe(&dampened)
+                    })
+                })
+                .count() as i32;
+            prop_assert_eq!(part_two(&reports), naive);
+        }
+    }
+}
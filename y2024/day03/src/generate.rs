@@ -0,0 +1,55 @@
+use common::random::Rng;
+
+/// Instructions looking like valid `mul(X,Y)`, but corrupted
+const CORRUPTED: [&str; 6] = [
+    "mul(4*",
+    "mul(6,9!",
+    "?(12,34)",
+    "mul ( 2 , 4 )",
+    "mul[3,7]",
+    "mul(1234,5)",
+];
+
+/// Characters scattered between the instructions
+const NOISE: &[u8] = b"!@#$%^&*()[]{}<>?,;:'+-_ who select from when mul do";
+
+/// Corrupted memory of 'lines' lines holding 'instructions' instructions each, mixing valid `mul`,
+/// `do()` and `don't()` instructions with corrupted ones and noise
+pub fn input(seed: u64, lines: usize, instructions: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut text = String::new();
+
+    for _ in 0..lines {
+        for _ in 0..instructions {
+            for _ in 0..rng.below(5) {
+                text.push(*rng.choose(NOISE) as char);
+            }
+            match rng.below(10) {
+                0 => text.push_str("do()"),
+                1 => text.push_str("don't()"),
+                2 | 3 => text.push_str(rng.choose::<&str>(&CORRUPTED)),
+                _ => text.push_str(&format!(
+                    "mul({},{})",
+                    rng.range(1..=999),
+                    rng.range(1..=999)
+                )),
+            }
+        }
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part_one, part_two};
+
+    #[test]
+    fn parses_and_solves() {
+        let memory = parse(input(3, 6, 200).as_bytes()).unwrap();
+        assert_eq!(memory.len(), 6);
+        let (all, enabled) = (part_one(&memory).unwrap(), part_two(&memory).unwrap());
+        assert!(0 < enabled && enabled < all);
+    }
+}
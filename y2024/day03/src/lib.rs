@@ -0,0 +1,153 @@
+/*
+    You can find the challenge description here: https://adventofcode.com/2024/day/3
+*/
+use std::io::BufRead;
+
+use common::error::{self, Error, Result};
+use common::input::DayFiles;
+use common::lint;
+use common::solution::Solution;
+use regex::{Match, Regex};
+
+pub mod generate;
+
+/// Puzzle input files of the day
+pub const FILES: DayFiles = common::day_files!(2024, 3, "input_test.txt");
+
+// Execute a multiplication of type mul(num_1, num_2) found in line 'line' of the memory, returns total + num_1*num_2
+fn mul_numbers(re: &Regex, input_str: &str, line: usize, matching_mul: Match, total: i128) -> Result<i128>{
+    let captures = re.captures(matching_mul.as_str()).expect("Matches are multiplications");
+    let (one, two) = (captures.get(1).expect("Operand one"), captures.get(2).expect("Operand two"));
+    // Errors point at an operand, the product and the total are reported at the second one
+    let error = |operand: Match, message: String| Error::Parse {
+        day: FILES.name,
+        line,
+        column: input_str[..matching_mul.start() + operand.start()].chars().count() + 1,
+        text: operand.as_str().to_string(),
+        message,
+    };
+
+    let op_one = one.as_str().parse::<i128>().map_err(|e| error(one, e.to_string()))?;
+    let op_two = two.as_str().parse::<i128>().map_err(|e| error(two, e.to_string()))?;
+    op_one
+        .checked_mul(op_two)
+        .ok_or_else(|| error(two, "the product overflows".to_string()))?
+        .checked_add(total)
+        .ok_or_else(|| error(two, "the total overflows".to_string()))
+}
+
+/// Reads the corrupted memory, one string per line
+pub fn parse(reader: impl BufRead) -> Result<Vec<String>>{
+    error::lines(FILES.name, reader).map(|line| Ok(line?.text)).collect()
+}
+
+/// Checks that the operands of every multiplication fit in a number, reporting every problem found
+pub fn check(reader: impl BufRead) -> Vec<Error> {
+    lint::check(FILES.name, reader, |lines| {
+        lint::each_line(lines, |text| part_one(&parse(text)?))
+    })
+}
+
+pub fn part_one(memory: &[String]) -> Result<i128>{
+    let re = Regex::new(r"mul\((\d+),(\d+)\)").expect("Invalid regex");
+    let mut total: i128 = 0;
+
+    for (i, input_str) in memory.iter().enumerate(){
+        for matching_mul in re.find_iter(input_str) {
+            total = mul_numbers(&re, input_str, i + 1, matching_mul, total)?;
+        }
+    }
+    Ok(total)
+}
+
+
+pub fn part_two(memory: &[String]) -> Result<i128>{
+    let re = Regex::new(r"mul\((\d+),(\d+)\)|do\(\)|don't\(\)").expect("Invalid regex");
+    let mut total: i128 = 0;
+    let mut active = true;
+
+    for (i, input_str) in memory.iter().enumerate(){
+        for matching_string in re.find_iter(input_str) {
+
+            match  matching_string.as_str() {
+                "do()" => {
+                    active = true; // Re-start counting matching multiplications
+                }
+                "don't()" => {
+                    active = false; // Stop counting matching multiplication
+                }
+                _ => {
+                    if active{
+                        total = mul_numbers(&re, input_str, i + 1, matching_string, total)?;
+                    }
+                }
+            }
+            
+        }
+    }
+    Ok(total)
+}
+
+/// Sum of the multiplications in the corrupted memory, then of the enabled ones only
+#[derive(Default)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<String>;
+    type Answer = i128;
+
+    const FILES: &'static DayFiles = &FILES;
+
+    fn check(&self, reader: impl BufRead) -> Vec<Error> {
+        check(reader)
+    }
+
+    fn parse(&self, reader: impl BufRead) -> Result<Vec<String>> {
+        parse(reader)
+    }
+
+    fn part_one(&self, input: &Vec<String>) -> Result<i128> {
+        part_one(input)
+    }
+
+    fn part_two(&self, input: &Vec<String>) -> Result<i128> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::Answers;
+    use common::input;
+
+    #[test]
+    fn example() {
+        let lines = parse(input::example(&FILES).unwrap()).unwrap();
+        assert_eq!(part_one(&lines).unwrap(), 161);
+        assert_eq!(part_two(&lines).unwrap(), 48);
+    }
+
+    #[test]
+    fn reports_overflows() {
+        let operand = "99999999999999999999999";
+        let memory = format!("xmul(2,4)mul({0},{0})\n", operand);
+        let expected = format!("y2024_day03: line 1, column 38: '{}': the product overflows", operand);
+        let lines = parse(memory.as_bytes()).unwrap();
+        assert_eq!(part_one(&lines).unwrap_err().to_string(), expected);
+        assert_eq!(part_two(&lines).unwrap_err().to_string(), expected);
+        assert_eq!(check(memory.as_bytes())[0].to_string(), expected);
+
+        let memory = format!("mul(99999999999999999999,{0})mul(99999999999999999999,{0})\n", "999999999999999999");
+        assert!(part_one(&parse(memory.as_bytes()).unwrap()).unwrap_err().to_string().ends_with("the total overflows"));
+    }
+
+    #[test]
+    #[ignore = "needs the real input and the answers file"]
+    fn real_input() {
+        let answers = Answers::load(&FILES).unwrap();
+        let lines = parse(input::real(&FILES).unwrap()).unwrap();
+        answers.check(1, part_one(&lines).unwrap());
+        answers.check(2, part_two(&lines).unwrap());
+    }
+}
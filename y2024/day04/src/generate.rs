@@ -0,0 +1,29 @@
+use common::random::Rng;
+
+/// Word search of 'width' by 'height' letters taken from `XMAS`
+pub fn input(seed: u64, width: usize, height: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let letters = ['X', 'M', 'A', 'S'];
+
+    (0..height)
+        .map(|_| {
+            let mut row: String = (0..width).map(|_| *rng.choose(&letters)).collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part_one, part_two, Config};
+
+    #[test]
+    fn parses_and_solves() {
+        let matrix = parse(input(4, 60, 40).as_bytes()).unwrap();
+        assert_eq!((matrix.width(), matrix.height()), (60, 40));
+        assert!(part_one(&matrix, &Config::default()) > 0);
+        assert!(part_two(&matrix, &Config::default()) > 0);
+    }
+}
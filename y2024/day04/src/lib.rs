@@ -0,0 +1,184 @@
+/*
+    You can find the challenge description here: https://adventofcode.com/2024/day/4
+*/
+use std::io::BufRead;
+
+use common::error::{self, Error, Result};
+use common::config;
+use common::input::{DayFiles, InputArgs};
+use common::lint;
+use common::solution::Solution;
+use grid::Grid;
+use serde::Deserialize;
+
+pub mod generate;
+
+/// Puzzle input files of the day
+pub const FILES: DayFiles = common::day_files!(2024, 4, "test_input.txt");
+
+/// Words searched by the puzzle, read from the `[y2024_day04]` table of the config file
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Word counted in every direction by part one
+    pub word: String,
+    /// Word of odd length crossed over itself in an X by part two
+    pub cross: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { word: "XMAS".to_string(), cross: "MAS".to_string() }
+    }
+}
+
+impl Config {
+    /// Loads the words selected by 'args', rejecting a cross word without a middle letter
+    pub fn load(args: &InputArgs) -> std::result::Result<Self, Box<dyn std::error::Error>> {
+        let config: Config = config::load(&FILES, args)?;
+        if config.cross.chars().count().is_multiple_of(2) {
+            return Err(format!("The cross word '{}' must have an odd length", config.cross).into());
+        }
+        Ok(config)
+    }
+}
+
+/// Parses the word search as a grid of characters
+pub fn parse(reader: impl BufRead) -> Result<Grid<char>>{
+    let lines = error::lines(FILES.name, reader).collect::<Result<Vec<_>>>()?;
+    Grid::parse_lines(lines.iter().map(|line| &line.text), |_, c| c)
+        .map_err(|e| lines[e.row].wrong_width(e.expected))
+}
+
+/// Checks that the word search is a rectangle of letters, reporting every problem found
+pub fn check(reader: impl BufRead) -> Vec<Error> {
+    lint::check(FILES.name, reader, |lines| {
+        lint::grid(FILES.name, lines, |c| c.is_alphabetic(), "a letter")
+    })
+}
+
+// Counts the occurrences of 'word', forwards and backwards, in a line of the word search
+fn count_word<'a>(line: impl Iterator<Item = &'a char>, word: &str) -> i32 {
+    let line: String = line.collect();
+    let backwards: String = word.chars().rev().collect();
+    let count = line.matches(word).count();
+    // A palindrome reads the same both ways, and must only be counted once
+    if backwards == word { count as i32 } else { (count + line.matches(&backwards).count()) as i32 }
+}
+
+pub fn part_one(matrix: &Grid<char>, config: &Config) -> i32{
+    let word = config.word.as_str();
+    let horizontal: i32 = matrix.rows().map(|row| count_word(row.iter(), word)).sum();
+    let vertical: i32 = matrix.columns().map(|column| count_word(column, word)).sum();
+    let diagonal: i32 = matrix.diagonals().map(|diagonal| count_word(diagonal, word)).sum();
+    let anti_diagonal: i32 = matrix.anti_diagonals().map(|diagonal| count_word(diagonal, word)).sum();
+
+    horizontal + vertical + diagonal + anti_diagonal
+}
+
+pub fn part_two(matrix: &Grid<char>, config: &Config) -> i32{
+    let cross: Vec<char> = config.cross.chars().collect();
+    if cross.len().is_multiple_of(2) {
+        return 0;
+    }
+    let half = (cross.len() / 2) as isize;
+
+    let mut tot = 0;
+    for (position, &cell) in matrix.iter(){
+        if cell != cross[half as usize] {
+            continue;
+        }
+
+        // Letters of the diagonal going through the center along (d_row, d_col)
+        let diagonal = |(d_row, d_col): (isize, isize)| -> Option<Vec<char>> {
+            (-half..=half).map(|i| matrix.step(position, (i * d_row, i * d_col)).map(|p| matrix[p])).collect()
+        };
+        /* Match, for MAS:
+           M S     S S     M M     S M
+            A  ,    A  ,    A  ,    A
+           M S     M M     S S     S M
+        */
+        let is_cross = |letters: Option<Vec<char>>| {
+            letters.is_some_and(|letters| letters == cross || letters.iter().rev().eq(cross.iter()))
+        };
+        if is_cross(diagonal((1, 1))) && is_cross(diagonal((1, -1))) {
+            tot+=1;
+        }
+    }
+    tot
+}
+
+/// Occurrences of the word of the config in the word search, then of its cross word in an X
+#[derive(Default)]
+pub struct Solver {
+    config: Config,
+}
+
+impl Solution for Solver {
+    type Input = Grid<char>;
+    type Answer = i32;
+
+    const FILES: &'static DayFiles = &FILES;
+
+    fn new(args: &InputArgs) -> std::result::Result<Self, Box<dyn std::error::Error>> {
+        Ok(Solver { config: Config::load(args)? })
+    }
+
+    fn check(&self, reader: impl BufRead) -> Vec<Error> {
+        check(reader)
+    }
+
+    fn parse(&self, reader: impl BufRead) -> Result<Grid<char>> {
+        parse(reader)
+    }
+
+    fn part_one(&self, input: &Grid<char>) -> Result<i32> {
+        Ok(part_one(input, &self.config))
+    }
+
+    fn part_two(&self, input: &Grid<char>) -> Result<i32> {
+        Ok(part_two(input, &self.config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::Answers;
+    use common::input;
+
+    #[test]
+    fn example() {
+        let matrix = parse(input::example(&FILES).unwrap()).unwrap();
+        assert_eq!(part_one(&matrix, &Config::default()), 18);
+        assert_eq!(part_two(&matrix, &Config::default()), 9);
+    }
+
+    #[test]
+    fn other_words() {
+        let matrix = parse("ABCBA\nXBXBX\nABCCA\n".as_bytes()).unwrap();
+        let config = Config { word: "ABC".to_string(), cross: "ABC".to_string() };
+        assert_eq!(part_one(&matrix, &config), 7);
+        assert_eq!(part_two(&matrix, &config), 2);
+        let config = Config { word: "BXB".to_string(), cross: "ABBA".to_string() };
+        assert_eq!(part_one(&matrix, &config), 2);
+        assert_eq!(part_two(&matrix, &config), 0);
+    }
+
+    #[test]
+    fn rejects_even_cross_words() {
+        let args = |cross: &str| InputArgs { set: vec![format!("cross={}", cross)], ..Default::default() };
+        assert_eq!(Solver::new(&args("MA")).err().unwrap().to_string(), "The cross word 'MA' must have an odd length");
+        assert!(Solver::new(&args("")).is_err());
+        assert_eq!(Solver::new(&args("ABC")).unwrap().config.cross, "ABC");
+    }
+
+    #[test]
+    #[ignore = "needs the real input and the answers file"]
+    fn real_input() {
+        let answers = Answers::load(&FILES).unwrap();
+        let matrix = parse(input::real(&FILES).unwrap()).unwrap();
+        answers.check(1, part_one(&matrix, &Config::default()));
+        answers.check(2, part_two(&matrix, &Config::default()));
+    }
+}
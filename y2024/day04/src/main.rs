@@ -0,0 +1,24 @@
+use std::error::Error;
+use std::process::ExitCode;
+
+use common::input::{self, InputArgs};
+use common::output::Report;
+use y2024_day04::{parse, part_one, part_two, Config, FILES};
+
+fn main() -> ExitCode {
+    common::trace::init();
+    common::error::report(run())
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let args = InputArgs::from_env()?;
+    let config = Config::load(&args)?;
+    let mut report = Report::new(&FILES, &args);
+    let reader = input::select(&FILES, &args)?;
+    let matrix = report.parse(|| parse(reader))?;
+
+    report.part(1, "Total (part one)", || part_one(&matrix, &config));
+    report.part(2, "Total (part two)", || part_two(&matrix, &config));
+
+    Ok(())
+}
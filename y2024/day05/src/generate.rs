@@ -0,0 +1,54 @@
+use common::random::Rng;
+
+/// Manual with 'pages' pages, at most 90, and 'updates' updates.
+///
+/// The pages follow a random order and every pair of pages gets a rule, so that any update can be
+/// sorted. About half of the updates are already in order.
+pub fn input(seed: u64, pages: usize, updates: usize) -> String {
+    assert!((1..=90).contains(&pages), "Pages are numbered 10 to 99");
+    let mut rng = Rng::new(seed);
+
+    let mut order: Vec<usize> = (10..100).collect();
+    rng.shuffle(&mut order);
+    order.truncate(pages);
+
+    let mut rules: Vec<(usize, usize)> = (0..pages)
+        .flat_map(|i| (i + 1..pages).map(move |j| (i, j)))
+        .collect();
+    rng.shuffle(&mut rules);
+    let mut text: String = rules
+        .iter()
+        .map(|&(i, j)| format!("{}|{}\n", order[i], order[j]))
+        .collect();
+    text.push('\n');
+
+    // Updates have an odd number of pages, so that they have a middle page
+    let max_len = (pages.min(23) - 1) / 2;
+    for _ in 0..updates {
+        let mut update: Vec<usize> = (0..pages).collect();
+        rng.shuffle(&mut update);
+        update.truncate(2 * rng.below(max_len + 1) + 1);
+        if rng.chance(0.5) {
+            update.sort();
+        }
+
+        let update: Vec<String> = update.iter().map(|&i| order[i].to_string()).collect();
+        text.push_str(&update.join(","));
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part_one, part_two};
+
+    #[test]
+    fn parses_and_solves() {
+        let manual = parse(input(5, 49, 200).as_bytes()).unwrap();
+        assert_eq!(manual.updates.len(), 200);
+        assert!(part_one(&manual).unwrap() > 0);
+        assert!(part_two(&manual).unwrap() > 0);
+    }
+}
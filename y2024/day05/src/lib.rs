@@ -0,0 +1,268 @@
+/*
+    You can find the challenge description here: https://adventofcode.com/2024/day/5
+*/
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+
+use common::error::{self, Error, Line, Result};
+use common::input::DayFiles;
+use common::lint;
+use common::solution::Solution;
+
+pub mod generate;
+
+/// Puzzle input files of the day
+pub const FILES: DayFiles = common::day_files!(2024, 5, "test_input.txt");
+
+/// Page ordering rules, mapping each page to the set of pages that must follow it, and the updates to print
+pub struct Manual {
+    pub ordering_rules: HashMap<i32, HashSet<i32>>,
+    pub updates: Vec<Vec<i32>>,
+}
+
+/// Parses the ordering rules followed by the list of updates
+pub fn parse(reader: impl BufRead) -> Result<Manual> {
+    let mut lines = error::lines(FILES.name, reader);
+    let ordering_rules = parse_ordering(&mut lines)?;
+
+    let mut updates = Vec::new();
+    for line in lines {
+        let line = line?;
+        if line.text.trim().is_empty() {
+            continue;
+        }
+
+        updates.push(
+            line.text
+                .split(',')
+                .map(|num_str| line.parse::<i32>(num_str.trim()))
+                .collect::<Result<_>>()?,
+        );
+    }
+
+    Ok(Manual {
+        ordering_rules,
+        updates,
+    })
+}
+
+/// Checks that every update has a middle page and that the rules order every pair of its pages,
+/// reporting every problem found
+pub fn check(reader: impl BufRead) -> Vec<Error> {
+    lint::check(FILES.name, reader, |lines| {
+        let mut problems = Vec::new();
+        let mut rules = HashSet::new();
+        let mut in_rules = true;
+
+        for line in lines {
+            if line.text.trim().is_empty() {
+                in_rules = false;
+                continue;
+            }
+
+            // Rules come first, until the first line that is not a rule
+            if in_rules {
+                if let Some((before, after)) = line.text.split_once('|') {
+                    match (line.parse::<i32>(before.trim()), line.parse::<i32>(after.trim())) {
+                        (Ok(before), Ok(after)) => {
+                            rules.insert((before, after));
+                        }
+                        (Err(e), _) | (_, Err(e)) => problems.push(e),
+                    }
+                    continue;
+                }
+                in_rules = false;
+            }
+
+            let tokens: Vec<&str> = line.text.split(',').map(str::trim).collect();
+            let pages = match tokens.iter().map(|token| line.parse::<i32>(token)).collect::<Result<Vec<_>>>() {
+                Ok(pages) => pages,
+                Err(e) => {
+                    problems.push(e);
+                    continue;
+                }
+            };
+
+            if pages.len() % 2 == 0 {
+                problems.push(line.error(&line.text, format!("expected an odd number of pages, found {}", pages.len())));
+            }
+            let unordered = (0..pages.len())
+                .flat_map(|j| (0..j).map(move |i| (i, j)))
+                .find(|&(i, j)| !rules.contains(&(pages[i], pages[j])) && !rules.contains(&(pages[j], pages[i])));
+            if let Some((i, j)) = unordered {
+                problems.push(line.error(tokens[j], format!("no rule orders pages {} and {}", pages[i], pages[j])));
+            }
+        }
+        problems
+    })
+}
+
+// Parse input ordering rules, stopping at the first line that is not a rule
+fn parse_ordering(
+    lines: &mut impl Iterator<Item = Result<Line>>,
+) -> Result<HashMap<i32, HashSet<i32>>> {
+    let mut ordering_rules: HashMap<i32, HashSet<i32>> = HashMap::new();
+    for line in lines {
+        let line = line?;
+        let numbers = line.text
+            .split('|')
+            .map(|num| num.trim())
+            .collect::<Vec<&str>>();
+
+        if numbers.len() != 2 {
+            // We have already parsed all ordering rules
+            break;
+        }
+
+        let (num_0, num_1) = (line.parse::<i32>(numbers[0])?, line.parse::<i32>(numbers[1])?);
+        ordering_rules
+            .entry(num_0)
+            .and_modify(|set| {
+                (*set).insert(num_1);
+            })
+            .or_insert({
+                HashSet::from([num_1])
+            });
+    }
+    Ok(ordering_rules)
+}
+
+// Finds ordering relation between 'a' and 'b' using input ordering rules
+fn find_ordering(
+    ordering_rules: &HashMap<i32, HashSet<i32>>,
+    a: i32,
+    b: i32,
+) -> Result<std::cmp::Ordering> {
+    
+    // b is in set of numbers smaller than a, pages without rules have no smaller numbers
+    if ordering_rules.get(&a).is_some_and(|smaller| smaller.contains(&b)){
+        return Ok(std::cmp::Ordering::Greater);
+    }
+
+    // a is in set of numbers smaller than b
+    if ordering_rules.get(&b).is_some_and(|smaller| smaller.contains(&a)){
+        return Ok(std::cmp::Ordering::Less);
+    }
+
+    Err(Error::invalid(FILES.name, format!("cannot determine the ordering of pages {} and {}", a, b)))
+}
+
+// Checks whether all pages in 'nums' respect the input ordering rules
+fn is_ordered(
+    ordering_rules: &HashMap<i32, HashSet<i32>>,
+    nums: &[i32],
+) -> Result<bool> {
+    let mut valid = true;
+    for i in 1..nums.len() {
+        if find_ordering(ordering_rules, nums[i - 1], nums[i])?
+            != std::cmp::Ordering::Greater
+        {
+            valid = false;
+        }
+    }
+    Ok(valid)
+}
+
+// Solves part one summing the mid element of all in-order lines
+pub fn part_one(manual: &Manual) -> Result<i32> {
+    let mut tot = 0;
+
+    for nums in &manual.updates {
+        if is_ordered(&manual.ordering_rules, nums)? {
+            tot += nums[nums.len() / 2];
+        }
+    }
+    Ok(tot)
+}
+
+// Solves part two sorting all out-of-order lines
+pub fn part_two(manual: &Manual) -> Result<i32> {
+    let ordering_rules = &manual.ordering_rules;
+    let mut tot = 0;
+    for line in &manual.updates {
+        if is_ordered(ordering_rules, line)? {
+            continue;
+        }
+
+        // Sort line using the input ordering rules and add mid element to total
+        let mut line = line.clone();
+        let mut error = None;
+        line.sort_by(|&a, &b| {
+            find_ordering(ordering_rules, b, a).unwrap_or_else(|e| {
+                error.get_or_insert(e);
+                std::cmp::Ordering::Equal
+            })
+        });
+        if let Some(e) = error {
+            return Err(e);
+        }
+        tot += line[line.len() / 2];
+    }
+    Ok(tot)
+}
+
+/// Middle pages of the updates already in order, then of the reordered ones
+#[derive(Default)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Manual;
+    type Answer = i32;
+
+    const FILES: &'static DayFiles = &FILES;
+
+    fn check(&self, reader: impl BufRead) -> Vec<Error> {
+        check(reader)
+    }
+
+    fn parse(&self, reader: impl BufRead) -> Result<Manual> {
+        parse(reader)
+    }
+
+    fn part_one(&self, input: &Manual) -> Result<i32> {
+        part_one(input)
+    }
+
+    fn part_two(&self, input: &Manual) -> Result<i32> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::Answers;
+    use common::input;
+
+    #[test]
+    fn example() {
+        let manual = parse(input::example(&FILES).unwrap()).unwrap();
+        assert_eq!(part_one(&manual).unwrap(), 143);
+        assert_eq!(part_two(&manual).unwrap(), 123);
+    }
+
+    #[test]
+    fn check_reports_every_update() {
+        let problems = check("47|53\n97|13\n\n47,53,97\n97,13\n53,47,x\n".as_bytes());
+
+        let found: Vec<String> = problems.iter().map(Error::to_string).collect();
+        assert_eq!(
+            found,
+            [
+                "y2024_day05: line 4, column 7: '97': no rule orders pages 47 and 97",
+                "y2024_day05: line 5, column 1: '97,13': expected an odd number of pages, found 2",
+                "y2024_day05: line 6, column 7: 'x': invalid digit found in string",
+            ]
+        );
+        assert!(check(input::example(&FILES).unwrap()).is_empty());
+    }
+
+    #[test]
+    #[ignore = "needs the real input and the answers file"]
+    fn real_input() {
+        let answers = Answers::load(&FILES).unwrap();
+        let manual = parse(input::real(&FILES).unwrap()).unwrap();
+        answers.check(1, part_one(&manual).unwrap());
+        answers.check(2, part_two(&manual).unwrap());
+    }
+}
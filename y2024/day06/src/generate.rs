@@ -0,0 +1,40 @@
+use common::random::Rng;
+
+/// Lab of 'width' by 'height' cells, about one in ten being an obstruction, with the guard facing
+/// up on a free cell
+pub fn input(seed: u64, width: usize, height: usize) -> String {
+    assert!(
+        width > 0 && height > 0,
+        "The lab needs a cell for the guard"
+    );
+    let mut rng = Rng::new(seed);
+
+    let mut cells: Vec<Vec<char>> = (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| if rng.chance(0.1) { '#' } else { '.' })
+                .collect()
+        })
+        .collect();
+    cells[rng.below(height)][rng.below(width)] = '^';
+
+    cells
+        .iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part_one, part_two};
+
+    #[test]
+    fn parses_and_solves() {
+        let lab = parse(input(6, 40, 30).as_bytes()).unwrap();
+        assert_eq!((lab.map.width(), lab.map.height()), (40, 30));
+        assert_eq!(lab.guard, '^');
+        assert_ne!(part_one(&lab), 0);
+        part_two(&lab);
+    }
+}
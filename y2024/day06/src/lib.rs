@@ -0,0 +1,263 @@
+/*
+    You can find the challenge description here: https://adventofcode.com/2024/day/6
+*/
+
+use std::io::BufRead;
+use std::iter;
+
+use animation::Frame;
+use common::error::{self, Error, Result};
+use common::input::DayFiles;
+use common::lint;
+use common::solution::Solution;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use grid::{Grid, Position};
+use tracing::debug;
+
+pub mod generate;
+
+/// Puzzle input files of the day
+pub const FILES: DayFiles = common::day_files!(2024, 6, "test_input.txt");
+
+// Offset of a step of the guard, along with the guard it turns into when facing an obstacle
+fn guard_moves(guard: char) -> Option<((isize, isize), char)> {
+    match guard {
+        '^' => Some(((-1, 0), '>')),
+        '>' => Some(((0, 1), 'v')),
+        'v' => Some(((1, 0), '<')),
+        '<' => Some(((0, -1), '^')),
+        _ => None,
+    }
+}
+
+/// Map of the lab with the starting (row, column) coordinates and label of the guard
+pub struct Lab {
+    pub map: Grid<bool>,
+    pub guard_position: Position,
+    pub guard: char,
+}
+
+/// Parses input map saving obstacle as true and anything else as false, along with the guard's position and label.
+pub fn parse(reader: impl BufRead) -> Result<Lab> {
+    let mut guard_position = None;
+    let mut guard = ' ';
+    let mut error = None;
+
+    let lines = error::lines(FILES.name, reader).collect::<Result<Vec<_>>>()?;
+    let map = Grid::parse_lines(lines.iter().map(|line| &line.text), |(row, col), ch| match ch {
+        '.' => false,
+        '#' => true,
+        '^' | '>' | 'v' | '<' if guard_position.is_none() => {
+            guard_position = Some((row, col));
+            guard = ch;
+            false
+        }
+        _ => {
+            let message = if matches!(ch, '^' | '>' | 'v' | '<') { "second guard" } else { "invalid cell" };
+            error.get_or_insert_with(|| lines[row].error_at(col + 1, &ch.to_string(), message));
+            false
+        }
+    })
+    .map_err(|e| lines[e.row].wrong_width(e.expected))?;
+
+    if let Some(e) = error {
+        return Err(e);
+    }
+
+    Ok(Lab {
+        map,
+        guard_position: guard_position.ok_or_else(|| Error::invalid(FILES.name, "the map has no guard"))?,
+        guard,
+    })
+}
+
+/// Checks that the map is a rectangle of free cells and obstructions holding a single guard, reporting every problem found
+pub fn check(reader: impl BufRead) -> Vec<Error> {
+    lint::check(FILES.name, reader, |lines| {
+        let mut problems = lint::grid(FILES.name, lines, |c| ".#^>v<".contains(c), "'.', '#' or a guard");
+        problems.extend(lint::exactly_one(FILES.name, lines, |c| "^>v<".contains(c), "guard"));
+        problems
+    })
+}
+
+// Walks the guard out of the map, setting all visited positions in 'visited' to the guard's direction. Returns -1 if the guard gets stuck in a loop
+fn walk(map: &Grid<bool>, visited: &mut Grid<char>, guard_position: &Position, mut guard: char) -> i32 {
+    let mut count_steps = 1;
+    let mut position = *guard_position;
+    visited[position] = guard;
+
+    while let Some((offset, turned)) = guard_moves(guard) {
+        // The guard leaves the map
+        let Some(next) = map.step(position, offset) else {
+            return count_steps;
+        };
+
+        if map[next] {
+            debug!(row = position.0, column = position.1, from = %guard, to = %turned, "guard turns");
+            guard = turned;
+        } else {
+            if visited[next] == '\0' {
+                count_steps += 1;
+                visited[next] = guard;
+            } else if visited[next] == guard {
+                return -1;
+            }
+            position = next;
+        }
+    }
+
+    unreachable!("The guard '{}' is not one parse accepts", guard)
+}
+
+pub fn part_one(lab: &Lab) -> i32 {
+    let mut visited = Grid::filled(lab.map.width(), lab.map.height(), '\0');
+    walk(&lab.map, &mut visited, &lab.guard_position, lab.guard)
+}
+
+// Checks whether an obstacle added at 'position' traps the guard in a loop, 'map' is left unchanged
+fn loops_with_obstacle(map: &mut Grid<bool>, lab: &Lab, position: Position) -> bool {
+    map[position] = true;
+    let mut visited = Grid::filled(map.width(), map.height(), '\0');
+    let loops = walk(map, &mut visited, &lab.guard_position, lab.guard) == -1;
+    map[position] = false;
+    loops
+}
+
+pub fn part_two(lab: &Lab) -> i32 {
+    let map = &lab.map;
+
+    // Only cells visited without new obstacles can change the guard's path
+    let mut visited = Grid::filled(map.width(), map.height(), '\0');
+    walk(map, &mut visited, &lab.guard_position, lab.guard);
+
+    // Ignore all cells with an obstacle or that were never visited
+    let candidates: Vec<Position> = map
+        .positions()
+        .filter(|&position| !map[position] && visited[position] != '\0')
+        .collect();
+
+    // Every thread works on its own copy of the map
+    #[cfg(feature = "parallel")]
+    let loops = candidates
+        .par_iter()
+        .map_init(|| map.clone(), |map, &position| loops_with_obstacle(map, lab, position))
+        .filter(|&loops| loops)
+        .count();
+    #[cfg(not(feature = "parallel"))]
+    let loops = {
+        let mut map = map.clone();
+        candidates
+            .iter()
+            .filter(|&&position| loops_with_obstacle(&mut map, lab, position))
+            .count()
+    };
+
+    loops as i32
+}
+
+/// Frames of the guard walking out of the lab, one per step or turn, with the visited cells marked with 'X'
+pub fn guard_walk(lab: &Lab) -> impl Iterator<Item = Frame> + '_ {
+    let map = &lab.map;
+    let mut picture = map.map(|&obstacle| if obstacle { '#' } else { '.' });
+    let mut visited = Grid::filled(map.width(), map.height(), '\0');
+    let mut guard = Some((lab.guard_position, lab.guard));
+    let (mut moves, mut count_visited) = (0, 1);
+    picture[lab.guard_position] = lab.guard;
+    visited[lab.guard_position] = lab.guard;
+
+    let start = Frame::new("move 0: 1 cell visited", &picture);
+    iter::once(start).chain(iter::from_fn(move || {
+        let (position, direction) = guard?;
+        let (offset, turned) = guard_moves(direction)?;
+        moves += 1;
+
+        let event = match map.step(position, offset) {
+            None => {
+                guard = None;
+                picture[position] = 'X';
+                ", the guard leaves the lab"
+            }
+            Some(next) if map[next] => {
+                guard = Some((position, turned));
+                picture[position] = turned;
+                ""
+            }
+            Some(next) if visited[next] == direction => {
+                guard = None;
+                ", the guard walks in a loop"
+            }
+            Some(next) => {
+                if visited[next] == '\0' {
+                    count_visited += 1;
+                    visited[next] = direction;
+                }
+                guard = Some((next, direction));
+                picture[position] = 'X';
+                picture[next] = direction;
+                ""
+            }
+        };
+        Some(Frame::new(format!("move {}: {} cells visited{}", moves, count_visited, event), &picture))
+    }))
+}
+
+/// Cells the guard patrols before leaving the lab, then the obstructions trapping it in a loop
+#[derive(Default)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Lab;
+    type Answer = i32;
+
+    const FILES: &'static DayFiles = &FILES;
+
+    fn check(&self, reader: impl BufRead) -> Vec<Error> {
+        check(reader)
+    }
+
+    fn parse(&self, reader: impl BufRead) -> Result<Lab> {
+        parse(reader)
+    }
+
+    fn part_one(&self, input: &Lab) -> Result<i32> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Lab) -> Result<i32> {
+        Ok(part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::Answers;
+    use common::input;
+
+    #[test]
+    fn example() {
+        let lab = parse(input::example(&FILES).unwrap()).unwrap();
+        assert_eq!(part_one(&lab), 41);
+        assert_eq!(part_two(&lab), 6);
+    }
+
+    #[test]
+    fn guard_walk_frames() {
+        let lab = parse(input::example(&FILES).unwrap()).unwrap();
+        let frames: Vec<Frame> = guard_walk(&lab).collect();
+        assert_eq!(frames[0].picture.lines().nth(6), Some(".#..^....."));
+        let last = frames.last().unwrap();
+        assert!(last.caption.ends_with("41 cells visited, the guard leaves the lab"), "{}", last.caption);
+        assert_eq!(last.picture.matches('X').count(), 41);
+    }
+
+    #[test]
+    #[ignore = "needs the real input and the answers file"]
+    fn real_input() {
+        let answers = Answers::load(&FILES).unwrap();
+        let lab = parse(input::real(&FILES).unwrap()).unwrap();
+        answers.check(1, part_one(&lab));
+        answers.check(2, part_two(&lab));
+    }
+}
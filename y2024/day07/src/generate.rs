@@ -0,0 +1,47 @@
+use common::random::Rng;
+
+/// 'equations' equations of two to 'operands' operands between 1 and 99.
+///
+/// Most test values are built by combining the operands with random operators, the others are
+/// nudged so that they may not be reachable. Panics above eight operands, which could overflow.
+pub fn input(seed: u64, equations: usize, operands: usize) -> String {
+    assert!(
+        (2..=8).contains(&operands),
+        "Equations have two to eight operands"
+    );
+    let mut rng = Rng::new(seed);
+    let mut text = String::new();
+
+    for _ in 0..equations {
+        let count = 2 + rng.below(operands - 1);
+        let values: Vec<i64> = (0..count).map(|_| rng.range(1..=99)).collect();
+        let mut target = values[1..]
+            .iter()
+            .fold(values[0], |acc, &value| match rng.below(3) {
+                0 => acc + value,
+                1 => acc * value,
+                _ => acc * 10i64.pow(value.ilog10() + 1) + value,
+            });
+        if rng.chance(0.3) {
+            target += rng.range(1..=9);
+        }
+
+        let values: Vec<String> = values.iter().map(i64::to_string).collect();
+        text.push_str(&format!("{}: {}\n", target, values.join(" ")));
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part_one, part_two};
+
+    #[test]
+    fn parses_and_solves() {
+        let equations = parse(input(7, 300, 8).as_bytes()).unwrap();
+        assert_eq!(equations.len(), 300);
+        let (plus_times, with_concat) = (part_one(&equations), part_two(&equations));
+        assert!(0 < plus_times && plus_times < with_concat);
+    }
+}
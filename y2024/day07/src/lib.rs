@@ -0,0 +1,185 @@
+/*
+    Challenge description can be found here: https://adventofcode.com/2024/day/7
+*/
+
+use std::io::BufRead;
+
+use common::error::{self, Error, Result};
+use common::input::DayFiles;
+use common::lint;
+use common::solution::Solution;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+pub mod generate;
+
+/// Puzzle input files of the day
+pub const FILES: DayFiles = common::day_files!(2024, 7, "input_test.txt");
+
+/// Parses each equation as a list of numbers, starting with the target value
+pub fn parse(reader: impl BufRead) -> Result<Vec<Vec<i64>>> {
+    let mut result = vec![];
+
+    for line in error::lines(FILES.name, reader) {
+        let line = line?;
+        let mut num_strs = line.text.split_ascii_whitespace();
+
+        let target_str = num_strs.next().ok_or_else(|| line.missing("target value"))?;
+        let target = target_str
+            .strip_suffix(':')
+            .ok_or_else(|| line.error(target_str, "expected ':' after the target value"))?;
+        let mut nums = vec![line.parse::<i64>(target)?];
+
+        for num_str in num_strs {
+            let num = line.parse::<i64>(num_str)?;
+            // Concatenation needs the number of digits of every operand
+            if num <= 0 {
+                return Err(line.error(num_str, "operands must be positive"));
+            }
+            nums.push(num);
+        }
+
+        if nums.len() < 2 {
+            return Err(line.missing("operand"));
+        }
+        result.push(nums);
+    }
+
+    Ok(result)
+}
+
+/// Checks that every equation has a target value and positive operands, reporting every problem found
+pub fn check(reader: impl BufRead) -> Vec<Error> {
+    lint::check(FILES.name, reader, |lines| lint::each_line(lines, |text| parse(text)))
+}
+
+/// Checks if there exists an expression containing the given operands that returns the target
+fn exists_expression_part_one(target: i64, partial_eval: i64, operands: &[i64]) -> bool {
+    // If we have no more operands we must have hit the target
+    if operands.is_empty() {
+        return target == partial_eval;
+    }
+
+    // // Cannot get any valid solution
+    if target < partial_eval {
+        return false;
+    }
+
+   
+    exists_expression_part_one(target, partial_eval + operands[0], &operands[1..])  // Try reaching solution by adding ...
+        || exists_expression_part_one(target, partial_eval * operands[0], &operands[1..]) // or multiplying next operand
+}
+
+/// Checks if there exists an expression containing the given operands that returns the target
+fn exists_expression_part_two(target: i64, partial_eval: i64, operands: &[i64]) -> bool {
+    // If we have no more operands we must have hit the target
+    if operands.is_empty() {
+        return target == partial_eval;
+    }
+
+    // Cannot get any valid solution
+    if target < partial_eval {
+        return false;
+    }
+
+    // Shift for computing partial_eval||operands[0]
+    let shift = 10i64.pow(operands[0].ilog10() + 1);
+    
+    exists_expression_part_two(target, partial_eval + operands[0], &operands[1..]) // Try reaching solution by adding ...
+        || exists_expression_part_two(target, partial_eval * operands[0], &operands[1..]) // or multiplying ...
+        || exists_expression_part_two(target, partial_eval * shift + operands[0], &operands[1..]) // or concatenating next operand
+}
+
+// Solves part one
+// Iterates over the equations, which are solved independently
+#[cfg(feature = "parallel")]
+fn equations(nums: &[Vec<i64>]) -> rayon::slice::Iter<'_, Vec<i64>> {
+    nums.par_iter()
+}
+
+#[cfg(not(feature = "parallel"))]
+fn equations(nums: &[Vec<i64>]) -> std::slice::Iter<'_, Vec<i64>> {
+    nums.iter()
+}
+
+pub fn part_one(nums: &[Vec<i64>]) -> i64 {
+    equations(nums)
+        .filter_map(|line| {
+            if exists_expression_part_one(line[0], 0, &line[1..]) {
+                return Some(line[0]);
+            }
+            None
+        })
+        .sum()
+}
+
+// Solves part two
+pub fn part_two(nums: &[Vec<i64>]) -> i64 {
+    equations(nums)
+        .filter_map(|line| {
+            if exists_expression_part_two(line[0], 0, &line[1..]) {
+                return Some(line[0]);
+            }
+            None
+        })
+        .sum()
+}
+
+/// Calibration result of the equations made true by adding and multiplying, then by concatenating too
+#[derive(Default)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<Vec<i64>>;
+    type Answer = i64;
+
+    const FILES: &'static DayFiles = &FILES;
+
+    fn check(&self, reader: impl BufRead) -> Vec<Error> {
+        check(reader)
+    }
+
+    fn parse(&self, reader: impl BufRead) -> Result<Vec<Vec<i64>>> {
+        parse(reader)
+    }
+
+    fn part_one(&self, input: &Vec<Vec<i64>>) -> Result<i64> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Vec<Vec<i64>>) -> Result<i64> {
+        Ok(part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::Answers;
+    use common::input;
+
+    #[test]
+    fn example() {
+        let equations = parse(input::example(&FILES).unwrap()).unwrap();
+        assert_eq!(part_one(&equations), 3749);
+        assert_eq!(part_two(&equations), 11387);
+    }
+
+    #[test]
+    fn check_agrees_with_parse() {
+        let input = "190: 10 19\n\n3267: 81 40 27\n";
+        let error = parse(input.as_bytes()).err().unwrap();
+        let problems = check(input.as_bytes());
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].to_string(), error.to_string());
+    }
+
+    #[test]
+    #[ignore = "needs the real input and the answers file"]
+    fn real_input() {
+        let answers = Answers::load(&FILES).unwrap();
+        let equations = parse(input::real(&FILES).unwrap()).unwrap();
+        answers.check(1, part_one(&equations));
+        answers.check(2, part_two(&equations));
+    }
+}
@@ -0,0 +1,46 @@
+use common::random::Rng;
+
+/// Antenna frequencies, as in the puzzle
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Map of 'width' by 'height' cells with 'antennas' antennas on distinct cells, sharing
+/// 'frequencies' frequencies
+pub fn input(
+    seed: u64,
+    width: usize,
+    height: usize,
+    antennas: usize,
+    frequencies: usize,
+) -> String {
+    assert!(antennas <= width * height, "Too many antennas for the map");
+    assert!(
+        (1..=FREQUENCIES.len()).contains(&frequencies),
+        "There are 1 to 62 frequencies"
+    );
+    let mut rng = Rng::new(seed);
+
+    let mut cells = vec!['.'; width * height];
+    let mut positions: Vec<usize> = (0..cells.len()).collect();
+    rng.shuffle(&mut positions);
+    for &position in &positions[..antennas] {
+        cells[position] = FREQUENCIES[rng.below(frequencies)] as char;
+    }
+
+    cells
+        .chunks(width.max(1))
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part_one, part_two};
+
+    #[test]
+    fn parses_and_solves() {
+        let antennas = parse(input(8, 50, 50, 200, 30).as_bytes()).unwrap();
+        let (antinodes, harmonics) = (part_one(&antennas), part_two(&antennas));
+        assert!(0 < antinodes && antinodes < harmonics);
+    }
+}
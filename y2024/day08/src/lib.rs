@@ -1,22 +1,42 @@
+use common::error::{self, Error, Result};
+use common::input::DayFiles;
+use common::lint;
+use common::solution::Solution;
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
-use std::error::Error;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::usize;
+use std::io::BufRead;
 
-const INPUT_PATH: &str = "day_eight/src/input.txt";
+pub mod generate;
+
+pub type AntennaMap = HashMap<char, Vec<(usize, usize)>>;
+
+/// Antenna positions along with the size of the map
+pub struct Antennas {
+    pub map: AntennaMap,
+    pub map_height: usize,
+    pub map_width: usize,
+}
+
+/// Puzzle input files of the day
+pub const FILES: DayFiles = common::day_files!(2024, 8, "input_test.txt");
 
 /// Parse input map saving it as a dictionary label_of_antenna => {position_1, ..., position_n}
-fn parse_map(
-    reader: &mut BufReader<File>,
-    map_height: &mut usize,
-    map_width: &mut usize,
-) -> Result<HashMap<char, Vec<(usize, usize)>>, Box<dyn Error>> {
+pub fn parse(reader: impl BufRead) -> Result<Antennas> {
     let mut map = HashMap::new();
+    let (mut map_height, mut map_width) = (0, 0);
+
+    for (i, line) in error::lines(FILES.name, reader).enumerate() {
+        let line = line?;
 
-    for (i, line) in reader.lines().into_iter().enumerate() {
-        line?
+        // The map is as wide as its rows, which must all have the same width
+        let width = line.text.chars().count();
+        if i == 0 {
+            map_width = width;
+        } else if width != map_width {
+            return Err(line.wrong_width(map_width));
+        }
+
+        line.text
             .chars()
             .enumerate()
             .filter(|(_, c)| *c != '.')
@@ -25,11 +45,26 @@ fn parse_map(
                     .and_modify(|vec: &mut Vec<_>| vec.push((j, i)))
                     .or_insert(vec![(j, i)]);
             });
-        (*map_height) += 1;
-        (*map_width) += 1;
+        map_height += 1;
     }
 
-    Ok(map)
+    Ok(Antennas {
+        map,
+        map_height,
+        map_width,
+    })
+}
+
+/// Checks that the map is a rectangle of free cells and antennas, reporting every problem found
+pub fn check(reader: impl BufRead) -> Vec<Error> {
+    lint::check(FILES.name, reader, |lines| {
+        lint::grid(
+            FILES.name,
+            lines,
+            |c| c == '.' || c.is_ascii_alphanumeric(),
+            "'.' or an antenna (a letter or a digit)",
+        )
+    })
 }
 
 /// Counts first two antinodes alligned to input antennas
@@ -94,10 +129,11 @@ fn count_antinodes_part_two(
 }
 
 /// Solves second challenge
-fn part_one(map: &HashMap<char, Vec<(usize, usize)>>, map_height: usize, map_width: usize) -> i32 {
+pub fn part_one(antennas: &Antennas) -> i32 {
+    let (map, map_height, map_width) = (&antennas.map, antennas.map_height, antennas.map_width);
     let mut taken_positions = HashSet::new();
-    map.iter()
-        .map(|(_, vec)| {
+    map.values()
+        .map(|vec| {
             // For each antenna label, take all antenna pairs and count antinodes
             vec.iter()
                 .tuple_combinations()
@@ -117,10 +153,11 @@ fn part_one(map: &HashMap<char, Vec<(usize, usize)>>, map_height: usize, map_wid
 
 
 /// Solves second challenge
-fn part_two(map: &HashMap<char, Vec<(usize, usize)>>, map_height: usize, map_width: usize) -> i32 {
+pub fn part_two(antennas: &Antennas) -> i32 {
+    let (map, map_height, map_width) = (&antennas.map, antennas.map_height, antennas.map_width);
     let mut taken_positions = HashSet::new();
-    map.iter()
-        .map(|(_, vec)| {
+    map.values()
+        .map(|vec| {
             // For each antenna label, take all antenna pairs and count antinodes
             vec.iter()
                 .tuple_combinations()
@@ -138,21 +175,60 @@ fn part_two(map: &HashMap<char, Vec<(usize, usize)>>, map_height: usize, map_wid
         .sum()
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let mut reader = BufReader::new(File::open(INPUT_PATH)?);
-    let (mut map_height, mut map_width) = (0, 0);
+/// Antinodes of the antennas, then with their resonant harmonics
+#[derive(Default)]
+pub struct Solver;
 
-    let map = parse_map(&mut reader, &mut map_height, &mut map_width)?;
+impl Solution for Solver {
+    type Input = Antennas;
+    type Answer = i32;
 
-    println!(
-        "Result (part one): {}",
-        part_one(&map, map_height, map_width)
-    );
+    const FILES: &'static DayFiles = &FILES;
 
-    println!(
-        "Result (part two): {}",
-        part_two(&map, map_height, map_width)
-    );
+    fn check(&self, reader: impl BufRead) -> Vec<Error> {
+        check(reader)
+    }
 
-    Ok(())
+    fn parse(&self, reader: impl BufRead) -> Result<Antennas> {
+        parse(reader)
+    }
+
+    fn part_one(&self, input: &Antennas) -> Result<i32> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Antennas) -> Result<i32> {
+        Ok(part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::Answers;
+    use common::input;
+
+    #[test]
+    fn example() {
+        let antennas = parse(input::example(&FILES).unwrap()).unwrap();
+        assert_eq!(part_one(&antennas), 14);
+        assert_eq!(part_two(&antennas), 34);
+    }
+
+    #[test]
+    fn wide_map() {
+        // The antinode on the right lies beyond the height of the map, but within its width
+        let antennas = parse(".a.a......\n..........\n".as_bytes()).unwrap();
+        assert_eq!(antennas.map_width, 10);
+        assert_eq!(part_one(&antennas), 1);
+    }
+
+    #[test]
+    #[ignore = "needs the real input and the answers file"]
+    fn real_input() {
+        let answers = Answers::load(&FILES).unwrap();
+        let antennas = parse(input::real(&FILES).unwrap()).unwrap();
+        answers.check(1, part_one(&antennas));
+        answers.check(2, part_two(&antennas));
+    }
 }
@@ -0,0 +1,29 @@
+use common::random::Rng;
+
+/// Disk map of 'files' files of one to nine blocks, separated by zero to nine free blocks
+pub fn input(seed: u64, files: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut text = String::with_capacity(2 * files);
+
+    for i in 0..files {
+        if i > 0 {
+            text.push_str(&rng.range(0..=9).to_string());
+        }
+        text.push_str(&rng.range(1..=9).to_string());
+    }
+    text.push('\n');
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part_one, part_two};
+
+    #[test]
+    fn parses_and_solves() {
+        let disk_map = parse(input(9, 2000).as_bytes()).unwrap();
+        assert_eq!(disk_map.len(), 3999);
+        assert!(part_one(&disk_map) < part_two(&disk_map));
+    }
+}
@@ -0,0 +1,334 @@
+use std::borrow::BorrowMut;
+use std::collections::BTreeSet;
+use std::io::BufRead;
+
+use common::error::{self, Error, Result};
+use common::input::DayFiles;
+use common::lint;
+use common::solution::Solution;
+use tracing::debug;
+
+pub mod generate;
+
+/// Puzzle input files of the day
+pub const FILES: DayFiles = common::day_files!(2024, 9, "input_test.txt");
+
+/// Parses the dense disk map, alternating file and free space lengths
+pub fn parse(reader: impl BufRead) -> Result<Vec<i32>> {
+    let line = error::lines(FILES.name, reader)
+        .next()
+        .transpose()?
+        .filter(|line| !line.text.trim().is_empty())
+        .ok_or_else(|| Error::invalid(FILES.name, "the disk map is empty"))?;
+
+    // Ignore the trailing newline and spaces
+    let len = line.text.trim_end().chars().count();
+    line.chars()
+        .take(len)
+        .map(|(column, c)| {
+            c.to_digit(10)
+                .map(|c| c as i32)
+                .ok_or_else(|| line.error_at(column, &c.to_string(), "expected a digit"))
+        })
+        .collect()
+}
+
+/// Checks that the disk map is a single line of digits, reporting every problem found
+pub fn check(reader: impl BufRead) -> Vec<Error> {
+    lint::check(FILES.name, reader, |lines| lint::single_line(lines, |text| parse(text)))
+}
+
+fn to_previous_full_chunk(disk_map: &mut [i32], index_full_chunk: &mut usize, end: &mut usize) {
+    *index_full_chunk -= disk_map[*end] as usize; // Subtract all remaining elements in full chunk
+    *index_full_chunk -= disk_map[*end - 1] as usize; // Subtract all free spaces before full chunk
+    disk_map[*end] = 0;
+    *end -= 2; // Go to next full chunk
+}
+
+fn to_next_free_chunk(
+    disk_map: &mut [i32],
+    index_free_chunk: &mut usize,
+    start: &mut usize,
+    end: &usize,
+    check_sum: &mut i64,
+) {
+    *index_free_chunk += disk_map[*start] as usize;
+
+    // Compute checksum of the busy chunk in between two free chunks
+    if *start + 1 < *end {
+        let chunk_in_between = *start + 1;
+        let index_in_between = *index_free_chunk;
+        debug!(file = to_tag(chunk_in_between), at = index_in_between, blocks = disk_map[chunk_in_between], "file stays between two free chunks");
+        *check_sum += to_tag(chunk_in_between)
+            * compute_sum(index_in_between, disk_map[chunk_in_between] as usize);
+        *index_free_chunk += disk_map[chunk_in_between] as usize;
+        disk_map[chunk_in_between] = 0;
+    }
+
+    *start += 2;
+}
+
+// Sum of the positions of 'blocks' blocks starting at 'start_index', zero for an empty file
+fn compute_sum(start_index: usize, blocks: usize) -> i64 {
+    let (start_index, blocks) = (start_index as i64, blocks as i64);
+    (blocks * (2 * start_index + blocks - 1)) >> 1
+}
+
+fn to_tag(index: usize) -> i64 {
+    (index >> 1) as i64
+}
+
+pub fn part_one(disk_map: &[i32]) -> i64 {
+    let mut disk_map = disk_map.to_vec();
+    let mut check_sum = 0;
+
+    let mut start = 1; // First free entry
+    let mut end = if (disk_map.len() - 1).is_multiple_of(2) {
+        disk_map.len() - 1
+    } else {
+        disk_map.len() - 2
+    }; // Last occupied entry
+
+    let mut index_free_chunk = disk_map[0] as usize;
+    // One past the last occupied block, trailing free blocks excluded
+    let mut index_full_chunk = disk_map[..=end].iter().sum::<i32>() as usize;
+
+    while start < end {
+        // We have enough free spaces to move the entire full chunk
+        if disk_map[start] > disk_map[end] {
+            debug!(file = to_tag(end), blocks = disk_map[end], to = index_free_chunk, "whole file moved");
+
+            // Update checksum
+            check_sum += to_tag(end) * compute_sum(index_free_chunk, disk_map[end] as usize);
+
+            // Move all elements to free chunk
+            index_free_chunk += disk_map[end] as usize;
+            disk_map[start] -= disk_map[end];
+
+            // Go to previous full chunk
+            to_previous_full_chunk(&mut disk_map, &mut index_full_chunk, &mut end);
+        }
+        // We can only move some elements of last chunk
+        else {
+            debug!(file = to_tag(end), blocks = disk_map[start], to = index_free_chunk, "part of the file moved");
+
+            // Update checksum
+            check_sum += to_tag(end) * compute_sum(index_free_chunk, disk_map[start] as usize);
+
+            // Move all elements we can in free chunk
+            index_full_chunk -= disk_map[start] as usize;
+            disk_map[end] -= disk_map[start];
+
+            // If full chunk is empty go to previous full chunk
+            if disk_map[end] == 0 {
+                to_previous_full_chunk(&mut disk_map, &mut index_full_chunk, &mut end);
+            }
+
+            // Go to next free chunk
+            to_next_free_chunk(
+                &mut disk_map,
+                &mut index_free_chunk,
+                &mut start,
+                &end,
+                &mut check_sum,
+            );
+        }
+    }
+
+    // Empty any remaining element
+    check_sum += to_tag(end)
+        * compute_sum(index_full_chunk - disk_map[end] as usize, disk_map[end] as usize);
+
+    check_sum
+}
+
+pub fn part_two(disk_map: &[i32]) -> i64{
+    let mut free_ranges = BTreeSet::new();
+
+    disk_map
+        .iter()
+        .enumerate()
+        .fold(0, |start_idx, (i, &capacity)| {
+            if i % 2 == 1 && capacity != 0 {
+                let free_range = (start_idx as usize, (start_idx + capacity) as usize);
+                free_ranges.insert(free_range);
+            }
+            start_idx + capacity
+        });
+    
+    let mut end_index = disk_map.iter().map(|&el| el as usize).sum();
+    
+    disk_map.iter().enumerate().rev().map(|(i, &n_elements )|{
+        let n_elements = n_elements as usize;
+
+        let mut res = 0;
+
+        // Entry is occupied
+        if i!=0 && i%2==0 {
+
+            let mut selected_range = None;
+
+            for free_range in free_ranges.borrow_mut().iter() {
+                    
+                    // Free chunk must be in lower positions 
+                    if free_range.0 > end_index {
+                        break;
+                    }
+
+                    let capacity = free_range.1-free_range.0;
+
+                    // Free chunk is now large enough
+                    if capacity < n_elements{
+                        continue;
+                    }
+                    
+                    selected_range = Some(*free_range);
+                    
+                    // Compute checksum and exit loop
+                    res = to_tag(i) * compute_sum(free_range.0, n_elements);
+
+                    break;
+            }  
+
+            // If some free space was found, remove it from the set of free ranges 
+            if let Some(free_range) = selected_range {
+                debug!(file = to_tag(i), blocks = n_elements, to = free_range.0, "file moved");
+                free_ranges.remove(&free_range);
+                // Insert new free chunk with remaining free spaces
+                if (free_range.1-free_range.0) > n_elements {
+                    free_ranges.insert((free_range.0 + n_elements, free_range.1));
+                }
+            }
+
+            // If no free space was found, compute checksum of occupied chunk 
+            else {
+                debug!(file = to_tag(i), blocks = n_elements, "no free chunk large enough, file stays");
+                res = to_tag(i) * compute_sum(end_index-n_elements, n_elements);
+            }
+        }
+
+        // Decrease end_index and yield result
+        end_index -= n_elements;
+        res
+    }).sum()
+}
+
+/// Checksum of the disk compacted block by block, then file by file
+#[derive(Default)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<i32>;
+    type Answer = i64;
+
+    const FILES: &'static DayFiles = &FILES;
+
+    fn check(&self, reader: impl BufRead) -> Vec<Error> {
+        check(reader)
+    }
+
+    fn parse(&self, reader: impl BufRead) -> Result<Vec<i32>> {
+        parse(reader)
+    }
+
+    fn part_one(&self, input: &Vec<i32>) -> Result<i64> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Vec<i32>) -> Result<i64> {
+        Ok(part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::Answers;
+    use common::input;
+    use proptest::prelude::*;
+
+    #[test]
+    fn example() {
+        let disk_map = parse(input::example(&FILES).unwrap()).unwrap();
+        assert_eq!(part_one(&disk_map), 1928);
+        assert_eq!(part_two(&disk_map), 2858);
+    }
+
+    #[test]
+    fn zero_length_files() {
+        for (text, checksum) in [("0", 0), ("00", 0), ("1021", 3), ("0021", 1), ("12031", 2)] {
+            let disk_map = parse(text.as_bytes()).unwrap();
+            assert_eq!(part_one(&disk_map), checksum, "{}", text);
+            assert_eq!(part_two(&disk_map), checksum, "{}", text);
+        }
+    }
+
+    #[test]
+    #[ignore = "needs the real input and the answers file"]
+    fn real_input() {
+        let answers = Answers::load(&FILES).unwrap();
+        let disk_map = parse(input::real(&FILES).unwrap()).unwrap();
+        answers.check(1, part_one(&disk_map));
+        answers.check(2, part_two(&disk_map));
+    }
+
+    /// Reference part one: lays out every block, then moves the last file block to the first free one
+    fn naive_part_one(disk_map: &[i32]) -> i64 {
+        let mut blocks: Vec<Option<i64>> = disk_map
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &len)| {
+                let block = (i % 2 == 0).then_some(i as i64 / 2);
+                std::iter::repeat_n(block, len as usize)
+            })
+            .collect();
+
+        let mut free = 0;
+        while let Some(last) = blocks.pop() {
+            while free < blocks.len() && blocks[free].is_some() {
+                free += 1;
+            }
+            match (last, free < blocks.len()) {
+                (Some(id), true) => blocks[free] = Some(id),
+                (Some(id), false) => {
+                    blocks.push(Some(id));
+                    break;
+                }
+                (None, _) => {}
+            }
+        }
+
+        blocks
+            .iter()
+            .enumerate()
+            .map(|(position, id)| position as i64 * id.unwrap_or(0))
+            .sum()
+    }
+
+    /// Disk maps of files of zero to nine blocks, separated by zero to nine free blocks
+    fn disk_map() -> impl Strategy<Value = Vec<i32>> {
+        (prop::collection::vec((0..=9i32, 0..=9i32), 1..30), any::<bool>()).prop_map(
+            |(chunks, trailing_free)| {
+                let mut disk_map: Vec<i32> =
+                    chunks.iter().flat_map(|&(file, free)| [file, free]).collect();
+                if !trailing_free {
+                    disk_map.pop();
+                }
+                disk_map
+            },
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn part_one_matches_naive(disk_map in disk_map()) {
+            prop_assert_eq!(part_one(&disk_map), naive_part_one(&disk_map));
+        }
+
+        #[test]
+        fn part_one_matches_naive_on_generated(seed in any::<u64>(), files in 1..300usize) {
+            let disk_map = parse(generate::input(seed, files).as_bytes()).unwrap();
+            prop_assert_eq!(part_one(&disk_map), naive_part_one(&disk_map));
+        }
+    }
+}
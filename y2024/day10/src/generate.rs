@@ -0,0 +1,45 @@
+use common::random::Rng;
+
+/// Topographic map of 'width' by 'height' heights.
+///
+/// Hills of height nine are scattered over the map, the height going down by one at each step
+/// away from them, so that the map has many hiking trails. One height in ten is random instead.
+pub fn input(seed: u64, width: usize, height: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let peaks: Vec<(usize, usize)> = (0..(width * height / 60).max(1))
+        .map(|_| (rng.below(height.max(1)), rng.below(width.max(1))))
+        .collect();
+
+    let mut text = String::with_capacity((width + 1) * height);
+    for row in 0..height {
+        for col in 0..width {
+            let distance = peaks
+                .iter()
+                .map(|&(r, c)| r.abs_diff(row) + c.abs_diff(col))
+                .min()
+                .unwrap_or(0);
+            let cell = if rng.chance(0.1) {
+                rng.below(10)
+            } else {
+                9usize.saturating_sub(distance)
+            };
+            text.push_str(&cell.to_string());
+        }
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part_one, part_two};
+
+    #[test]
+    fn parses_and_solves() {
+        let map = parse(input(10, 60, 50).as_bytes()).unwrap();
+        assert_eq!((map.map.width(), map.map.height()), (60, 50));
+        let (score, rating) = (part_one(&map), part_two(&map));
+        assert!(0 < score && score <= rating);
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::HashSet;
+/*
+    You can find the challenge description here: https://adventofcode.com/2024/day/10
+*/
+use std::io::BufRead;
+
+use common::error::{self, Error, Result};
+use common::input::DayFiles;
+use common::lint;
+use common::solution::Solution;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use grid::{Grid, Position};
+use tracing::debug;
+
+pub mod generate;
+
+/// Puzzle input files of the day
+pub const FILES: DayFiles = common::day_files!(2024, 10, "input_test.txt");
+
+/// Topographic map with the positions of all 0-entries (starting points) and 9-entries (ending points)
+pub struct TopographicMap {
+    pub map: Grid<u32>,
+    pub starting_points: HashSet<Position>,
+    pub ending_points: HashSet<Position>,
+}
+
+/// Parses input map and fills the sets of starting and ending points.
+pub fn parse(reader: impl BufRead) -> Result<TopographicMap> {
+    let mut starting_points = HashSet::new();
+    let mut ending_points = HashSet::new();
+    let mut error = None;
+
+    let lines = error::lines(FILES.name, reader).collect::<Result<Vec<_>>>()?;
+    let map = Grid::parse_lines(lines.iter().map(|line| &line.text), |(i, j), ch| {
+        if ch == '0' {
+            starting_points.insert((i, j));
+        }
+        else if ch == '9'{
+            ending_points.insert((i,j));
+        }
+        ch.to_digit(10).unwrap_or_else(|| {
+            error.get_or_insert_with(|| lines[i].error_at(j + 1, &ch.to_string(), "expected a digit"));
+            0
+        })
+    })
+    .map_err(|e| lines[e.row].wrong_width(e.expected))?;
+
+    if let Some(e) = error {
+        return Err(e);
+    }
+
+    Ok(TopographicMap {
+        map,
+        starting_points,
+        ending_points,
+    })
+}
+
+/// Checks that the map is a rectangle of heights, reporting every problem found
+pub fn check(reader: impl BufRead) -> Vec<Error> {
+    lint::check(FILES.name, reader, |lines| {
+        lint::grid(FILES.name, lines, |c| c.is_ascii_digit(), "a height from 0 to 9")
+    })
+}
+
+/// Counts the number of trailheads from a single starting point
+fn count_trailheads(
+    map: &Grid<u32>,
+    ending_points: &mut HashSet<Position>,
+    current_point: &Position,
+    part_one: bool
+) -> i32 {
+    // We have completed a trail
+    if map[*current_point] == 9 {
+
+        // In part one we count all trails ending at a specific position as a single trail
+        if part_one && !ending_points.remove(current_point){
+            return 0;
+        }
+
+        return 1;
+    }
+
+    // Neighboring cells containing the next number in sequence
+    map.neighbours(*current_point)
+        .filter(|&neighbour| map[neighbour] == map[*current_point] + 1)
+        .map(|neighbour| count_trailheads(map, ending_points, &neighbour, part_one))
+        .sum()
+}
+
+// Iterates over the starting points, whose trails are followed independently
+#[cfg(feature = "parallel")]
+fn trailheads(starting_points: &HashSet<Position>) -> rayon::collections::hash_set::Iter<'_, Position> {
+    starting_points.par_iter()
+}
+
+#[cfg(not(feature = "parallel"))]
+fn trailheads(starting_points: &HashSet<Position>) -> std::collections::hash_set::Iter<'_, Position> {
+    starting_points.iter()
+}
+
+pub fn part_one(topographic_map: &TopographicMap) -> i32 {
+    let TopographicMap { map, starting_points, ending_points } = topographic_map;
+    trailheads(starting_points)
+        .map(|starting_point| {
+            let score = count_trailheads(map, &mut ending_points.clone(), starting_point, true);
+            debug!(row = starting_point.0, column = starting_point.1, score, "trailhead");
+            score
+        })
+        .sum()
+}
+
+pub fn part_two(topographic_map: &TopographicMap) -> i32 {
+    let TopographicMap { map, starting_points, ending_points } = topographic_map;
+    trailheads(starting_points)
+        .map(|starting_point| {
+            let score = count_trailheads(map, &mut ending_points.clone(), starting_point, false);
+            debug!(row = starting_point.0, column = starting_point.1, score, "trailhead");
+            score
+        })
+        .sum()
+}
+
+/// Scores of the trailheads on the topographic map, then their ratings
+#[derive(Default)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = TopographicMap;
+    type Answer = i32;
+
+    const FILES: &'static DayFiles = &FILES;
+
+    fn check(&self, reader: impl BufRead) -> Vec<Error> {
+        check(reader)
+    }
+
+    fn parse(&self, reader: impl BufRead) -> Result<TopographicMap> {
+        parse(reader)
+    }
+
+    fn part_one(&self, input: &TopographicMap) -> Result<i32> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &TopographicMap) -> Result<i32> {
+        Ok(part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::Answers;
+    use common::input;
+
+    #[test]
+    fn example() {
+        let topographic_map = parse(input::example(&FILES).unwrap()).unwrap();
+        assert_eq!(part_one(&topographic_map), 36);
+        assert_eq!(part_two(&topographic_map), 81);
+    }
+
+    #[test]
+    #[ignore = "needs the real input and the answers file"]
+    fn real_input() {
+        let answers = Answers::load(&FILES).unwrap();
+        let topographic_map = parse(input::real(&FILES).unwrap()).unwrap();
+        answers.check(1, part_one(&topographic_map));
+        answers.check(2, part_two(&topographic_map));
+    }
+}
@@ -0,0 +1,28 @@
+use common::random::Rng;
+
+/// 'stones' stones engraved with numbers of one to seven digits
+pub fn input(seed: u64, stones: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    let stones: Vec<String> = (0..stones)
+        .map(|_| {
+            let digits = rng.range(1..=7) as u32;
+            rng.range(0..=10i64.pow(digits) - 1).to_string()
+        })
+        .collect();
+    stones.join(" ") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part_one, part_two, Config};
+
+    #[test]
+    fn parses_and_solves() {
+        let stones = parse(input(11, 50).as_bytes()).unwrap();
+        assert_eq!(stones.values().sum::<u64>(), 50);
+        let config = Config::default();
+        assert!(part_one(&stones, &config).unwrap() < part_two(&stones, &config).unwrap());
+    }
+}
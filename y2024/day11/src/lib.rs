@@ -0,0 +1,180 @@
+/*
+You can find the challenge description here: https://adventofcode.com/2024/day/11
+*/
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use common::error::{self, Error, Result};
+use common::config;
+use common::input::{DayFiles, InputArgs};
+use common::lint;
+use common::solution::Solution;
+use serde::Deserialize;
+
+pub mod generate;
+
+/// Puzzle input files of the day
+pub const FILES: DayFiles = common::day_files!(2024, 11, "input_test.txt");
+
+/// Blinks of each part, read from the `[y2024_day11]` table of the config file
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub part_one_blinks: u32,
+    pub part_two_blinks: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { part_one_blinks: 25, part_two_blinks: 75 }
+    }
+}
+
+/// Parses the stones, counting the occurrences of each engraved number
+pub fn parse(reader: impl BufRead) -> Result<HashMap<u64, u64>> {
+    let line = error::lines(FILES.name, reader)
+        .next()
+        .transpose()?
+        .ok_or_else(|| Error::invalid(FILES.name, "there are no stones"))?;
+    let mut stones_to_count = HashMap::new();
+    for s in line.text.split_whitespace() {
+        let key = line.parse::<u64>(s)?;
+        stones_to_count
+            .entry(key)
+            .and_modify(|occ| *occ += 1)
+            .or_insert(1);
+    }
+    Ok(stones_to_count)
+}
+
+/// Checks that the stones are listed on a single line, reporting every problem found
+pub fn check(reader: impl BufRead) -> Vec<Error> {
+    lint::check(FILES.name, reader, |lines| lint::single_line(lines, |text| parse(text)))
+}
+
+/// Counts the stones after blinking 'n_blinks' times, reporting an engraved number or a number of
+/// stones that overflows a u64
+fn blink(stones_to_count: &HashMap<u64, u64>, n_blinks: u32) -> Result<u64> {
+    let overflow = |what: String| Error::invalid(FILES.name, format!("{} overflows", what));
+    let mut stones_to_count = stones_to_count.clone();
+
+    for blink in 1..=n_blinks {
+        let stones_to_count_old = std::mem::take(&mut stones_to_count);
+        let mut add = |num: u64, count: u64| -> Result<()> {
+            let old_count = stones_to_count.entry(num).or_insert(0);
+            *old_count = old_count
+                .checked_add(count)
+                .ok_or_else(|| overflow(format!("the number of stones {} after {} blinks", num, blink)))?;
+            Ok(())
+        };
+
+        for (&num, &count) in &stones_to_count_old {
+            let n_digits = if num != 0 { num.ilog10() + 1 } else { 1 };
+
+            // Split number in 2
+            if n_digits % 2 == 0 {
+                let base = 10u64.pow(n_digits >> 1);
+                add(num % base, count)?;
+                add(num / base, count)?;
+            }
+
+            // Replace 0s with 1s
+            else if num == 0 {
+                add(1, count)?;
+            }
+
+            // Multiply by 2024
+            else {
+                let product = num
+                    .checked_mul(2024)
+                    .ok_or_else(|| overflow(format!("the stone {} multiplied by 2024", num)))?;
+                add(product, count)?;
+            }
+        }
+    }
+
+    stones_to_count
+        .values()
+        .try_fold(0u64, |total, &count| total.checked_add(count))
+        .ok_or_else(|| overflow(format!("the number of stones after {} blinks", n_blinks)))
+}
+
+pub fn part_one(stones_to_count: &HashMap<u64, u64>, config: &Config) -> Result<u64> {
+    blink(stones_to_count, config.part_one_blinks)
+}
+
+pub fn part_two(stones_to_count: &HashMap<u64, u64>, config: &Config) -> Result<u64> {
+    blink(stones_to_count, config.part_two_blinks)
+}
+
+/// Stones after the blinks of each part, counted from the config
+#[derive(Default)]
+pub struct Solver {
+    config: Config,
+}
+
+impl Solution for Solver {
+    type Input = HashMap<u64, u64>;
+    type Answer = u64;
+
+    const FILES: &'static DayFiles = &FILES;
+
+    fn new(args: &InputArgs) -> std::result::Result<Self, Box<dyn std::error::Error>> {
+        let config = config::load(&FILES, args)?;
+        Ok(Solver { config })
+    }
+
+    fn check(&self, reader: impl BufRead) -> Vec<Error> {
+        check(reader)
+    }
+
+    fn parse(&self, reader: impl BufRead) -> Result<HashMap<u64, u64>> {
+        parse(reader)
+    }
+
+    fn part_one(&self, input: &HashMap<u64, u64>) -> Result<u64> {
+        part_one(input, &self.config)
+    }
+
+    fn part_two(&self, input: &HashMap<u64, u64>) -> Result<u64> {
+        part_two(input, &self.config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::Answers;
+    use common::input;
+
+    #[test]
+    fn example() {
+        let stones = parse(input::example(&FILES).unwrap()).unwrap();
+        assert_eq!(part_one(&stones, &Config::default()).unwrap(), 55312);
+        // The first blinks of the example are given in the puzzle
+        let config = Config { part_one_blinks: 6, part_two_blinks: 0 };
+        assert_eq!(part_one(&stones, &config).unwrap(), 22);
+        assert_eq!(part_two(&stones, &config).unwrap(), 2);
+    }
+
+    #[test]
+    fn reports_overflows() {
+        let config = Config { part_one_blinks: 1, part_two_blinks: 500 };
+        let stones = parse("9999999999999999999\n".as_bytes()).unwrap();
+        assert_eq!(
+            part_one(&stones, &config).unwrap_err().to_string(),
+            "y2024_day11: the stone 9999999999999999999 multiplied by 2024 overflows"
+        );
+        let stones = parse("0\n".as_bytes()).unwrap();
+        assert!(part_two(&stones, &config).unwrap_err().to_string().contains("the number of stones"));
+    }
+
+    #[test]
+    #[ignore = "needs the real input and the answers file"]
+    fn real_input() {
+        let answers = Answers::load(&FILES).unwrap();
+        let stones = parse(input::real(&FILES).unwrap()).unwrap();
+        answers.check(1, part_one(&stones, &Config::default()).unwrap());
+        answers.check(2, part_two(&stones, &Config::default()).unwrap());
+    }
+}
@@ -0,0 +1,39 @@
+use common::random::Rng;
+
+/// Garden of 'width' by 'height' plots with up to 'plants' kinds of plants, at most 26.
+///
+/// Plots mostly copy the plant above or on their left, growing regions of irregular shapes.
+pub fn input(seed: u64, width: usize, height: usize, plants: usize) -> String {
+    assert!((1..=26).contains(&plants), "Plants are labelled A to Z");
+    let mut rng = Rng::new(seed);
+    let mut garden = vec![vec!['A'; width]; height];
+
+    for row in 0..height {
+        for col in 0..width {
+            garden[row][col] = match rng.below(10) {
+                0..=3 if row > 0 => garden[row - 1][col],
+                4..=7 if col > 0 => garden[row][col - 1],
+                _ => (b'A' + rng.below(plants) as u8) as char,
+            };
+        }
+    }
+
+    garden
+        .iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part_one, part_two};
+
+    #[test]
+    fn parses_and_solves() {
+        let map = parse(input(12, 80, 40, 10).as_bytes()).unwrap();
+        assert_eq!((map.width(), map.height()), (80, 40));
+        let (perimeter_price, sides_price) = (part_one(&map), part_two(&map));
+        assert!(0 < sides_price && sides_price < perimeter_price);
+    }
+}
@@ -0,0 +1,201 @@
+/*
+You can find the challenge description here: https://adventofcode.com/2024/day/12
+*/
+use std::io::BufRead;
+
+use common::error::{self, Error, Result};
+use common::input::DayFiles;
+use common::lint;
+use common::solution::Solution;
+use grid::{Grid, Position, ORTHOGONAL};
+
+pub mod generate;
+
+/// Puzzle input files of the day
+pub const FILES: DayFiles = common::day_files!(2024, 12, "input_test.txt");
+
+/// Parses the garden plots as a grid of plant labels
+pub fn parse(reader: impl BufRead) -> Result<Grid<char>> {
+    let lines = error::lines(FILES.name, reader).collect::<Result<Vec<_>>>()?;
+    Grid::parse_lines(lines.iter().map(|line| &line.text), |_, ch| ch)
+        .map_err(|e| lines[e.row].wrong_width(e.expected))
+}
+
+/// Checks that the garden is a rectangle of plants, reporting every problem found
+pub fn check(reader: impl BufRead) -> Vec<Error> {
+    lint::check(FILES.name, reader, |lines| {
+        lint::grid(FILES.name, lines, |c| c.is_ascii_uppercase(), "a plant (an uppercase letter)")
+    })
+}
+
+fn visit_part_one(
+    map: &Grid<char>,
+    visited: &mut Grid<bool>,
+    point: Position,
+) -> (u32, u32) {
+    visited[point] = true;
+
+    let label = map[point];
+
+    let mut area = 1;
+    let mut perimeter = 0;
+
+    for delta in ORTHOGONAL {
+        match map.step(point, delta) {
+            Some(new_point) if map[new_point] == label => {
+                if !visited[new_point] {
+                    let (area_to_add, perimeter_to_add) = visit_part_one(map, visited, new_point);
+                    area += area_to_add;
+                    perimeter += perimeter_to_add;
+                }
+            }
+            _ => perimeter += 1,
+        }
+    }
+
+    (area, perimeter)
+}
+
+fn visit_part_two(
+    map: &Grid<char>,
+    visited: &mut Grid<bool>,
+    point: Position,
+) -> (u32, u32) {
+    visited[point] = true;
+
+    let label = map[point];
+
+    let mut area = 1;
+    let mut sides = 0;
+
+    // Whether the cell at 'delta' from the current one belongs to the same region
+    let same = |delta| map.step(point, delta).is_some_and(|p| map[p] == label);
+    let (up, down, left, right) = (same((-1, 0)), same((1, 0)), same((0, -1)), same((0, 1)));
+
+    // Look for external _|
+    if !down && !right {
+        sides += 1;
+    }
+    // Look for internal _|
+    if right && same((-1, 1)) && !up {
+        sides += 1;
+    }
+
+    // Look for external ¯|
+    if !up && !right {
+        sides += 1;
+    }
+    // Look for internal ¯|
+    if right && same((1, 1)) && !down {
+        sides += 1;
+    }
+
+    // Look for external |_
+    if !down && !left {
+        sides += 1;
+    }
+    // Look for internal |_
+    if left && same((-1, -1)) && !up {
+        sides += 1;
+    }
+
+    // Look for external |¯
+    if !up && !left {
+        sides += 1;
+    }
+    // Look for internal |¯
+    if left && same((1, -1)) && !down {
+        sides += 1;
+    }
+
+    // Look at the neighbours of the same region
+    for delta in ORTHOGONAL {
+        if let Some(new_point) = map.step(point, delta) {
+            if map[new_point] == label && !visited[new_point] {
+                let (area_to_add, sides_to_add) = visit_part_two(map, visited, new_point);
+                area += area_to_add;
+                sides += sides_to_add;
+            }
+        }
+    }
+
+    (area, sides)
+}
+
+pub fn part_one(map: &Grid<char>) -> u32 {
+    let mut visited = Grid::filled(map.width(), map.height(), false);
+    let mut sum = 0;
+
+    for point in map.positions() {
+        if !visited[point] {
+            let (area, perimeter) = visit_part_one(map, &mut visited, point);
+            sum += area * perimeter;
+        }
+    }
+
+    sum
+}
+
+pub fn part_two(map: &Grid<char>) -> u32 {
+    let mut visited = Grid::filled(map.width(), map.height(), false);
+    let mut sum = 0;
+
+    for point in map.positions() {
+        if !visited[point] {
+            let (area, sides) = visit_part_two(map, &mut visited, point);
+            sum += area * sides;
+        }
+    }
+
+    sum
+}
+
+/// Fencing price of the garden regions, by perimeter then by number of sides
+#[derive(Default)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Grid<char>;
+    type Answer = u32;
+
+    const FILES: &'static DayFiles = &FILES;
+
+    fn check(&self, reader: impl BufRead) -> Vec<Error> {
+        check(reader)
+    }
+
+    fn parse(&self, reader: impl BufRead) -> Result<Grid<char>> {
+        parse(reader)
+    }
+
+    fn part_one(&self, input: &Grid<char>) -> Result<u32> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Grid<char>) -> Result<u32> {
+        Ok(part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::Answers;
+    use common::input;
+
+    #[test]
+    fn example() {
+        let map = parse(input::example(&FILES).unwrap()).unwrap();
+        assert_eq!(part_one(&map), 772);
+        assert_eq!(part_two(&map), 436);
+    }
+
+    #[test]
+    #[ignore = "needs the real input and the answers file"]
+    fn real_input() {
+        let answers = Answers::load(&FILES).unwrap();
+        let map = parse(input::real(&FILES).unwrap()).unwrap();
+        answers.check(1, part_one(&map));
+        answers.check(2, part_two(&map));
+    }
+}
@@ -0,0 +1,46 @@
+use common::random::Rng;
+
+/// 'machines' claw machines with buttons moving 10 to 99 steps along each axis.
+///
+/// About half of the prizes can be won by pressing each button at most a hundred times, the
+/// others are placed at random.
+pub fn input(seed: u64, machines: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut text = String::new();
+
+    for i in 0..machines {
+        let a = (rng.range(10..=99), rng.range(10..=99));
+        let b = (rng.range(10..=99), rng.range(10..=99));
+        let prize = if rng.chance(0.5) {
+            let (presses_a, presses_b) = (rng.range(0..=100), rng.range(0..=100));
+            (
+                presses_a * a.0 + presses_b * b.0,
+                presses_a * a.1 + presses_b * b.1,
+            )
+        } else {
+            (rng.range(1000..=20000), rng.range(1000..=20000))
+        };
+
+        if i > 0 {
+            text.push('\n');
+        }
+        text.push_str(&format!("Button A: X+{}, Y+{}\n", a.0, a.1));
+        text.push_str(&format!("Button B: X+{}, Y+{}\n", b.0, b.1));
+        text.push_str(&format!("Prize: X={}, Y={}\n", prize.0, prize.1));
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part_one, part_two, Config};
+
+    #[test]
+    fn parses_and_solves() {
+        let machines = parse(input(13, 300).as_bytes()).unwrap();
+        assert_eq!(machines.len(), 300);
+        assert!(part_one(&machines) > 0);
+        part_two(&machines, &Config::default());
+    }
+}
@@ -0,0 +1,326 @@
+/*
+You can find the challenge description here: https://adventofcode.com/2024/day/13
+*/
+use common::error::{self, Error, Result};
+use common::config;
+use common::input::{DayFiles, InputArgs};
+use common::lint;
+use common::solution::Solution;
+use regex::Regex;
+use serde::Deserialize;
+use std::io::BufRead;
+
+pub mod generate;
+
+/// Puzzle input files of the day
+pub const FILES: DayFiles = common::day_files!(2024, 13, "input_test.txt");
+
+/// Claw machine with the (x, y) movements of buttons A and B and the position of the prize
+pub struct Machine {
+    pub buttons: Vec<Vec<i32>>,
+    pub prize: Vec<i32>,
+}
+
+/// Distance of the prizes in part two, read from the `[y2024_day13]` table of the config file
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Added to both coordinates of every prize
+    pub offset: i64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { offset: 10000000000000 }
+    }
+}
+
+fn solve(inputs: &[Vec<i128>], target: &[i128]) -> i128 {
+
+    let (x_t, y_t) = (target[0], target[1]);
+    let (x_1, y_1) = (inputs[0][0], inputs[0][1]);
+    let (x_2, y_2) = (inputs[1][0], inputs[1][1]);
+
+
+    // Get a and b solving the following system of equations:
+    // a*x_1 + b*x_2 = x_t
+    // a*y_1 + b*y_2 = y_t 
+
+    let div = x_1 * y_2 - x_2 * y_1;
+    let (a, b) = if div != 0 {
+        // The system has a single solution
+        let (a, b) = (x_t * y_2 - x_2 * y_t, x_1 * y_t - x_t * y_1);
+        // "a" and "b" must be non negative integers
+        if a % div != 0 || b % div != 0 || (a / div).is_negative() || (b / div).is_negative() {
+            return i128::MAX;
+        }
+        (a / div, b / div)
+    } else {
+        // The buttons move along the same line: solve on an axis they move along, then check the other one
+        let on_x = x_1 != 0 || x_2 != 0;
+        let (p_1, p_2, t) = if on_x { (x_1, x_2, x_t) } else { (y_1, y_2, y_t) };
+        match cheapest_presses(p_1, p_2, t) {
+            Some((a, b)) if a * x_1 + b * x_2 == x_t && a * y_1 + b * y_2 == y_t => (a, b),
+            _ => return i128::MAX,
+        }
+    };
+
+    a*3+b
+}
+
+/// Cheapest non negative 'a' and 'b' such that a*p_1 + b*p_2 = t, pressing A costing three tokens and B one
+fn cheapest_presses(p_1: i128, p_2: i128, t: i128) -> Option<(i128, i128)> {
+    if p_1 == 0 && p_2 == 0 {
+        return (t == 0).then_some((0, 0));
+    }
+
+    // Every solution is (a_0 + k*step_a, b_0 + k*step_b) for an integer k
+    let (g, u, v) = extended_gcd(p_1, p_2);
+    if t % g != 0 {
+        return None;
+    }
+    let (a_0, b_0) = (u * (t / g), v * (t / g));
+    let (step_a, step_b) = (p_2 / g, -p_1 / g);
+
+    // Range of k keeping both presses non negative, None when unbounded
+    let (mut low, mut high) = (None::<i128>, None::<i128>);
+    for (start, step) in [(a_0, step_a), (b_0, step_b)] {
+        match step.signum() {
+            0 if start < 0 => return None,
+            0 => {}
+            1 => low = Some(low.map_or(i128::MIN, |low| low).max(-start.div_euclid(step))),
+            _ => high = Some(high.map_or(i128::MAX, |high| high).min(start.div_euclid(-step))),
+        }
+    }
+    if let (Some(low), Some(high)) = (low, high) {
+        if low > high {
+            return None;
+        }
+    }
+
+    // The cost changes linearly with k, so the cheapest presses are at one end of the range
+    let k = if 3 * step_a + step_b > 0 { low.or(high) } else { high.or(low) }?;
+    Some((a_0 + k * step_a, b_0 + k * step_b))
+}
+
+/// Greatest common divisor 'g' of 'a' and 'b', positive, with 'u' and 'v' such that a*u + b*v = g
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a.abs(), a.signum(), 0);
+    }
+    let (g, u, v) = extended_gcd(b, a % b);
+    (g, v, u - (a / b) * v)
+}
+
+/// Tokens needed to win the prize of a single machine, or i128::MAX if it cannot be won
+fn tokens_part_one(inputs: &[Vec<i32>], target: &[i32]) -> i128 {
+    solve(
+        &inputs
+            .iter()
+            .map(|vec: &Vec<i32>| vec.iter().map(|&x| x as i128).collect())
+            .collect::<Vec<_>>(),
+        &target.iter().map(|&x| x as i128).collect::<Vec<_>>(),
+    )
+}
+
+/// Tokens needed to win the prize of a single machine moved 'offset' away, or i128::MAX if it cannot be won
+fn tokens_part_two(inputs: &[Vec<i32>], target: &[i32], offset: i64) -> i128 {
+    solve(
+        &inputs
+            .iter()
+            .map(|vec: &Vec<i32>| vec.iter().map(|&x| x as i128).collect())
+            .collect::<Vec<_>>(),
+        &target.iter().map(|&x| offset as i128 + x as i128).collect::<Vec<_>>(),
+    )
+}
+
+/// Parses the claw machines, three lines each
+pub fn parse(reader: impl BufRead) -> Result<Vec<Machine>> {
+    let mut count = 0;
+
+    let mut machines = Vec::new();
+    let mut inputs = Vec::new();
+
+    let re = Regex::new(r"[+-]?\d+").expect("Invalid regex");
+
+    for line in error::lines(FILES.name, reader) {
+        let line = line?;
+
+        if line.text.trim().is_empty() {
+            continue;
+        }
+        let numbers: Vec<&str> = re.find_iter(&line.text).map(|number| number.as_str()).collect();
+
+        // Every line has an X and a Y value
+        match numbers.len() {
+            0 => return Err(line.missing("X value")),
+            1 => return Err(line.missing("Y value")),
+            2 => {}
+            _ => return Err(line.error(numbers[2], "expected only X and Y values")),
+        }
+        let digits = numbers
+            .iter()
+            .map(|number| line.parse::<i32>(number))
+            .collect::<Result<Vec<i32>>>()?;
+
+        match count {
+            0..=1 => {
+                inputs.push(digits);
+            }
+
+            2 => {
+                machines.push(Machine {
+                    buttons: std::mem::take(&mut inputs),
+                    prize: digits,
+                });
+            }
+
+            _ => {
+                break;
+            }
+        }
+
+        count = (count + 1) % 3;
+    }
+
+    if count != 0 {
+        return Err(Error::invalid(FILES.name, "the last machine has no prize"));
+    }
+
+    Ok(machines)
+}
+
+/// Checks that the machines are listed as button A, button B and prize lines, reporting every
+/// problem found
+pub fn check(reader: impl BufRead) -> Vec<Error> {
+    const LINES: [(&str, char); 3] = [("Button A:", '+'), ("Button B:", '+'), ("Prize:", '=')];
+
+    lint::check(FILES.name, reader, |lines| {
+        let mut problems = Vec::new();
+        let lines: Vec<_> = lines.iter().filter(|line| !line.text.trim().is_empty()).collect();
+
+        for (i, line) in lines.iter().enumerate() {
+            let (label, sign) = LINES[i % 3];
+            let Some(values) = line.text.strip_prefix(label) else {
+                let found = line.text.split_whitespace().next().unwrap_or_default();
+                problems.push(line.error(found, format!("expected '{}'", label)));
+                continue;
+            };
+
+            let values: Vec<&str> = values.split(',').map(str::trim).collect();
+            if values.len() != 2 {
+                problems.push(line.error(&line.text, format!("expected 'X{}<number>, Y{}<number>'", sign, sign)));
+                continue;
+            }
+            for (axis, value) in ["X", "Y"].iter().zip(&values) {
+                match value.strip_prefix(&format!("{}{}", axis, sign)) {
+                    Some(number) => problems.extend(line.parse::<i32>(number).err()),
+                    None => problems.push(line.error(value, format!("expected '{}{}<number>'", axis, sign))),
+                }
+            }
+        }
+
+        if lines.len() % 3 != 0 {
+            problems.push(Error::invalid(FILES.name, "the last machine has no prize"));
+        }
+        problems
+    })
+}
+
+pub fn part_one(machines: &[Machine]) -> i128 {
+    machines
+        .iter()
+        .map(|machine| tokens_part_one(&machine.buttons, &machine.prize))
+        .filter(|&res| res != i128::MAX)
+        .sum()
+}
+
+pub fn part_two(machines: &[Machine], config: &Config) -> i128 {
+    machines
+        .iter()
+        .map(|machine| tokens_part_two(&machine.buttons, &machine.prize, config.offset))
+        .filter(|&res| res != i128::MAX)
+        .sum()
+}
+
+/// Fewest tokens winning the prizes of the claw machines, then with the offset of the config
+#[derive(Default)]
+pub struct Solver {
+    config: Config,
+}
+
+impl Solution for Solver {
+    type Input = Vec<Machine>;
+    type Answer = i128;
+
+    const FILES: &'static DayFiles = &FILES;
+
+    fn new(args: &InputArgs) -> std::result::Result<Self, Box<dyn std::error::Error>> {
+        let config = config::load(&FILES, args)?;
+        Ok(Solver { config })
+    }
+
+    fn check(&self, reader: impl BufRead) -> Vec<Error> {
+        check(reader)
+    }
+
+    fn parse(&self, reader: impl BufRead) -> Result<Vec<Machine>> {
+        parse(reader)
+    }
+
+    fn part_one(&self, input: &Vec<Machine>) -> Result<i128> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Vec<Machine>) -> Result<i128> {
+        Ok(part_two(input, &self.config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::Answers;
+    use common::input;
+
+    #[test]
+    fn example() {
+        let machines = parse(input::example(&FILES).unwrap()).unwrap();
+        assert_eq!(part_one(&machines), 480);
+        // Without the offset, part two is part one
+        assert_eq!(part_two(&machines, &Config { offset: 0 }), 480);
+    }
+
+    #[test]
+    fn collinear_buttons() {
+        let machine = "Button A: X+1, Y+1\nButton B: X+2, Y+2\nPrize: X=5, Y=5\n";
+        // One press of A and two of B
+        assert_eq!(part_one(&parse(machine.as_bytes()).unwrap()), 5);
+        let machine = "Button A: X+1, Y+1\nButton B: X+2, Y+2\nPrize: X=5, Y=6\n";
+        assert_eq!(part_one(&parse(machine.as_bytes()).unwrap()), 0);
+    }
+
+    #[test]
+    fn button_without_x_movement() {
+        let machine = "Button A: X+0, Y+3\nButton B: X+2, Y+1\nPrize: X=4, Y=5\n";
+        // One press of A and two of B
+        assert_eq!(part_one(&parse(machine.as_bytes()).unwrap()), 5);
+        assert_eq!(part_two(&parse(machine.as_bytes()).unwrap(), &Config::default()), 0);
+    }
+
+    #[test]
+    fn prize_far_away() {
+        let machine = "Button A: X+1, Y+0\nButton B: X+0, Y+1\nPrize: X=2000000000, Y=2000000000\n";
+        // Two billion presses of each button
+        assert_eq!(part_one(&parse(machine.as_bytes()).unwrap()), 8000000000);
+    }
+
+    #[test]
+    #[ignore = "needs the real input and the answers file"]
+    fn real_input() {
+        let answers = Answers::load(&FILES).unwrap();
+        let machines = parse(input::real(&FILES).unwrap()).unwrap();
+        answers.check(1, part_one(&machines));
+        answers.check(2, part_two(&machines, &Config::default()));
+    }
+}
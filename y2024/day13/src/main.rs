@@ -0,0 +1,25 @@
+use std::error::Error;
+use std::process::ExitCode;
+
+use common::config;
+use common::input::{self, InputArgs};
+use common::output::Report;
+use y2024_day13::{parse, part_one, part_two, Config, FILES};
+
+fn main() -> ExitCode {
+    common::trace::init();
+    common::error::report(run())
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let args = InputArgs::from_env()?;
+    let config: Config = config::load(&FILES, &args)?;
+    let mut report = Report::new(&FILES, &args);
+    let reader = input::select(&FILES, &args)?;
+    let machines = report.parse(|| parse(reader))?;
+
+    report.part(1, "Result (part one)", || part_one(&machines));
+    report.part(2, "Result (part two)", || part_two(&machines, &config));
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use common::random::Rng;
+
+/// 'robots' robots moving in a 'width' by 'height' area, plus a five by five square of robots
+/// forming at time 'tree_at', if any.
+///
+/// `part_two` is an error on the inputs without a tree in the 101 by 103 area of the puzzle.
+pub fn input(seed: u64, robots: usize, width: i64, height: i64, tree_at: Option<i64>) -> String {
+    assert!(width > 0 && height > 0, "The area cannot be empty");
+    let mut rng = Rng::new(seed);
+    let mut text = String::new();
+    let mut push = |position: (i64, i64), velocity: (i64, i64)| {
+        text.push_str(&format!(
+            "p={},{} v={},{}\n",
+            position.0, position.1, velocity.0, velocity.1
+        ));
+    };
+
+    for _ in 0..robots {
+        let position = (rng.range(0..=width - 1), rng.range(0..=height - 1));
+        let velocity = (
+            rng.range(1 - width..=width - 1),
+            rng.range(1 - height..=height - 1),
+        );
+        push(position, velocity);
+    }
+
+    if let Some(time) = tree_at {
+        let corner = (rng.range(0..=width - 1), rng.range(0..=height - 1));
+        for (dx, dy) in (0..5).flat_map(|dx| (0..5).map(move |dy| (dx, dy))) {
+            let velocity = (
+                rng.range(1 - width..=width - 1),
+                rng.range(1 - height..=height - 1),
+            );
+            // Walk back from the square to the position at time zero
+            let position = (
+                (corner.0 + dx - time * velocity.0).rem_euclid(width),
+                (corner.1 + dy - time * velocity.1).rem_euclid(height),
+            );
+            push(position, velocity);
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part_one, part_two, Config};
+
+    #[test]
+    fn parses_and_solves() {
+        let robots = parse(input(14, 300, 101, 103, Some(300)).as_bytes()).unwrap();
+        assert_eq!(robots.coordinates.len(), 325);
+        assert!(part_one(&robots, &Config::default()).unwrap() > 0);
+        let time = part_two(&robots, &Config::default()).unwrap();
+        assert!(0 < time && time <= 300);
+    }
+}
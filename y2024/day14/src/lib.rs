@@ -0,0 +1,358 @@
+use animation::Frame;
+use common::error::{self, Error, Result};
+use common::config;
+use common::input::{DayFiles, InputArgs};
+use common::lint;
+use common::solution::Solution;
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+pub mod generate;
+
+/// Starting coordinates and velocities of all robots
+pub struct Robots {
+    pub coordinates: Vec<Vec<i32>>,
+    pub velocities: Vec<Vec<i32>>,
+}
+
+/// Puzzle input files of the day
+pub const FILES: DayFiles = common::day_files!(2024, 14, "input_test.txt");
+
+/// Area of the robots and duration of part one, read from the `[y2024_day14]` table of the config file
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub width: i32,
+    pub height: i32,
+    /// Seconds after which part one measures the safety factor
+    pub seconds: i32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { width: 101, height: 103, seconds: 100 }
+    }
+}
+
+impl Config {
+    /// Loads the area selected by 'args', rejecting an empty one and one whose number of tiles, the
+    /// seconds part two may wait for, does not fit in an i32
+    pub fn load(args: &InputArgs) -> std::result::Result<Self, Box<dyn std::error::Error>> {
+        let config: Config = config::load(&FILES, args)?;
+        if config.width <= 0 || config.height <= 0 {
+            return Err(format!("The area must not be empty, found {}x{}", config.width, config.height).into());
+        }
+        if config.width.checked_mul(config.height).is_none() {
+            return Err(format!("The area is too large, found {}x{}", config.width, config.height).into());
+        }
+        Ok(config)
+    }
+}
+
+fn draw_map(map: &HashMap<(i32, i32), i32>, config: &Config) -> String {
+    let mut picture = String::new();
+    for i in 0..config.width {
+        for j in 0..config.height {
+            match map.get(&(i, j)) {
+                Some(entry) if *entry != 0 => {
+                    picture.push_str(&entry.to_string());
+                }
+                _ => {
+                    picture.push('.');
+                }
+            }
+        }
+        picture.push('\n');
+    }
+    picture
+}
+
+/// Draws the robots as they are positioned after 'time' seconds
+pub fn draw_tree(robots: &Robots, time: i32, config: &Config) -> String {
+    let map = robots
+        .coordinates
+        .iter()
+        .zip(&robots.velocities)
+        .fold(HashMap::new(), |mut acc, (c, v)| {
+            let x = position(c[0], v[0], time, config.width);
+            let y = position(c[1], v[1], time, config.height);
+            *acc.entry((x, y)).or_insert(0) += 1;
+            acc
+        });
+    draw_map(&map, config)
+}
+
+/// Coordinate in 0..limit of a robot starting at 'start' after 'time' seconds at 'velocity', computed
+/// in i64 as the distance travelled does not fit in an i32
+fn position(start: i32, velocity: i32, time: i32, limit: i32) -> i32 {
+    (start as i64 + time as i64 * velocity as i64).rem_euclid(limit as i64) as i32
+}
+
+/// Frames of the robots moving, one per second, endlessly
+pub fn robot_frames<'a>(robots: &'a Robots, config: &'a Config) -> impl Iterator<Item = Frame> + 'a {
+    (0..).map(move |time| Frame::new(format!("{} seconds", time), draw_tree(robots, time, config)))
+}
+
+fn is_valid_tree(map: &HashMap<(i32, i32), i32>, config: &Config) -> bool {
+    let mut found = false;
+    let threshold = 1;
+
+    'outer: for (x, y) in map.keys() {
+        // Try to find many robots disposed over the diagonal lines centered in (x,y). As this implies a dense area, a threshold of 1 is sufficient for seeing the tree.
+        for c in 0..config.width {
+            let down_left = map.get(&(x - c, y + c)).unwrap_or(&0);
+            let down_right = map.get(&(x + c, y + c)).unwrap_or(&0);
+            let up_left = map.get(&(x - c, y - c)).unwrap_or(&0);
+            let up_right = map.get(&(x + c, y - c)).unwrap_or(&0);
+
+            // We are not in a dense area
+            if *up_left == 0 || *up_right == 0 || *down_left == 0 || *down_right == 0 {
+                break;
+            }
+
+            // We have found a dense area
+            if c > threshold {
+                found = true;
+                break 'outer;
+            }
+        }
+    }
+
+    found
+}
+
+/// Seconds until the robots first form a tree. The robots are back to their starting positions
+/// every 'width * height' seconds, so an input without a tree is an error once they all have been seen
+pub fn part_two(robots: &Robots, config: &Config) -> Result<i32> {
+    let (coordinates, velocity_vec) = (&robots.coordinates, &robots.velocities);
+
+    let mut time = 1;
+    let mut new_coordinates = coordinates.to_vec();
+    let mut map = new_coordinates.iter().fold(HashMap::new(), |mut acc, c| {
+        *acc.entry((c[0], c[1])).or_insert(0) += 1;
+        acc
+    });
+
+    while time <= config.width * config.height {
+        new_coordinates
+            .iter_mut()
+            .zip(velocity_vec)
+            .for_each(|(c, v)| {
+                let entry = map.get_mut(&(c[0], c[1])).unwrap();
+                if *entry == 1 {
+                    map.remove(&(c[0], c[1]));
+                } else {
+                    *entry -= 1;
+                }
+
+                c[0] = position(c[0], v[0], 1, config.width);
+                c[1] = position(c[1], v[1], 1, config.height);
+                *map.entry((c[0], c[1])).or_insert(0) += 1;
+            });
+
+        if is_valid_tree(&map, config) {
+            return Ok(time);
+        }
+
+        time += 1;
+    }
+
+    Err(Error::invalid(FILES.name, "the robots never form a tree"))
+}
+
+pub fn part_one(robots: &Robots, config: &Config) -> Result<i64> {
+    safety_factor(robots, config.width, config.height, config.seconds)
+}
+
+/// Multiplies the number of robots in each quadrant of a 'x_limit' by 'y_limit' area after 'time' seconds
+fn safety_factor(robots: &Robots, x_limit: i32, y_limit: i32, time: i32) -> Result<i64> {
+    let (coordinates, velocities) = (&robots.coordinates, &robots.velocities);
+
+    // Get middle column and row (assumimg odd number of rows/columns)
+    let (mid_x, mid_y) = (x_limit/2, y_limit/2); 
+
+    // Counters for each quadrant
+    let (mut up_left, mut up_right, mut down_left, mut down_right) = (0i64, 0, 0, 0);
+
+
+    for (coordinate, velocity) in coordinates.iter().zip(velocities){
+        let res_x = position(coordinate[0], velocity[0], time, x_limit);
+        let res_y = position(coordinate[1], velocity[1], time, y_limit);
+        
+        // Assign drone to quadrant (if possible)
+        if res_x < mid_x {
+            if res_y < mid_y {
+                up_left += 1;
+            } else if res_y > mid_y {
+                down_left += 1;
+            }
+        } else if res_x > mid_x {
+            if res_y < mid_y {
+                up_right += 1;
+            } else if res_y > mid_y {
+                down_right += 1;
+            }
+        }
+    
+    }
+
+    // Return safety factor
+    [up_right, down_left, down_right]
+        .iter()
+        .try_fold(up_left, |factor, &count| factor.checked_mul(count))
+        .ok_or_else(|| Error::invalid(FILES.name, "the safety factor overflows"))
+}
+
+/// Parses robots returning their starting coordinates and velocities
+pub fn parse(reader: impl BufRead) -> Result<Robots> {
+    let re = Regex::new(r"([a-z]+)=([-]?\d+),([-]?\d+)").expect("Invalid regex");
+
+    let mut coordinates = Vec::new();
+    let mut velocities = Vec::new();
+
+    for line in error::lines(FILES.name, reader) {
+        let line = line?;
+        let captures = re.captures_iter(&line.text);
+        let mut coordinate = Vec::new();
+        let mut velocity = Vec::new();
+
+        for capture in captures {
+            let values = match &capture[1] {
+                "p" => &mut coordinate,
+                "v" => &mut velocity,
+                _ => return Err(line.error(&capture[1], "expected 'p' or 'v'")),
+            };
+            values.extend([line.parse::<i32>(&capture[2])?, line.parse::<i32>(&capture[3])?]);
+        }
+
+        if coordinate.len() != 2 || velocity.len() != 2 {
+            return Err(line.error(&line.text, "expected one position 'p=X,Y' and one velocity 'v=X,Y'"));
+        }
+
+        coordinates.push(coordinate);
+        velocities.push(velocity);
+    }
+
+    Ok(Robots {
+        coordinates,
+        velocities,
+    })
+}
+
+/// Checks that every robot has a position inside the default area and a velocity, reporting every problem found
+pub fn check(reader: impl BufRead) -> Vec<Error> {
+    check_with(reader, &Config::default())
+}
+
+/// Same as 'check', in the area given by 'config'
+pub fn check_with(reader: impl BufRead, config: &Config) -> Vec<Error> {
+    lint::check(FILES.name, reader, |lines| {
+        let mut problems = lint::each_line(lines, |text| parse(text));
+
+        for line in lines {
+            // Lines that do not parse, empty ones included, are already reported
+            let Some(position) = parse(line.text.as_bytes())
+                .ok()
+                .and_then(|robots| robots.coordinates.into_iter().next())
+            else {
+                continue;
+            };
+            if !(0..config.width).contains(&position[0]) || !(0..config.height).contains(&position[1]) {
+                let start = line.text.find("p=").unwrap_or(0);
+                let token = line.text[start..].split_whitespace().next().unwrap_or_default();
+                problems.push(line.error(token, format!("position outside of the {}x{} area", config.width, config.height)));
+            }
+        }
+        problems
+    })
+}
+
+/// Safety factor of the robots after the seconds of the config, then the seconds until they form a tree
+#[derive(Default)]
+pub struct Solver {
+    config: Config,
+}
+
+impl Solution for Solver {
+    type Input = Robots;
+    type Answer = i64;
+
+    const FILES: &'static DayFiles = &FILES;
+
+    fn new(args: &InputArgs) -> std::result::Result<Self, Box<dyn std::error::Error>> {
+        Ok(Solver { config: Config::load(args)? })
+    }
+
+    fn check(&self, reader: impl BufRead) -> Vec<Error> {
+        check_with(reader, &self.config)
+    }
+
+    fn parse(&self, reader: impl BufRead) -> Result<Robots> {
+        parse(reader)
+    }
+
+    fn part_one(&self, input: &Robots) -> Result<i64> {
+        part_one(input, &self.config)
+    }
+
+    fn part_two(&self, input: &Robots) -> Result<i64> {
+        part_two(input, &self.config).map(i64::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::Answers;
+    use common::input;
+
+    #[test]
+    fn example() {
+        let robots = parse(input::example(&FILES).unwrap()).unwrap();
+        // The example robots move in an 11 by 7 area, and part two has no example answer
+        let config = Config { width: 11, height: 7, ..Config::default() };
+        assert_eq!(part_one(&robots, &config).unwrap(), 12);
+        assert!(part_two(&robots, &config).is_err());
+        assert!(check_with(input::example(&FILES).unwrap(), &config).is_empty());
+        assert_eq!(check_with("p=11,6 v=1,1\n".as_bytes(), &config).len(), 1);
+    }
+
+    #[test]
+    fn check_reports_empty_lines() {
+        let problems = check("p=0,0 v=1,1\n\np=1,1 v=1,1\n".as_bytes());
+        let found: Vec<String> = problems.iter().map(Error::to_string).collect();
+        assert_eq!(
+            found,
+            ["y2024_day14: line 2, column 1: expected one position 'p=X,Y' and one velocity 'v=X,Y'"]
+        );
+    }
+
+    #[test]
+    fn rejects_empty_areas() {
+        let args = InputArgs { set: vec!["width=0".to_string()], ..Default::default() };
+        assert_eq!(Solver::new(&args).err().unwrap().to_string(), "The area must not be empty, found 0x103");
+        let args = InputArgs { set: vec!["width=100000".to_string(), "height=100000".to_string()], ..Default::default() };
+        assert_eq!(Solver::new(&args).err().unwrap().to_string(), "The area is too large, found 100000x100000");
+    }
+
+    #[test]
+    fn fast_robots() {
+        // A hundred seconds at the largest velocities overflow an i32
+        let robots = parse("p=0,0 v=2147483647,-2147483647\n".as_bytes()).unwrap();
+        assert_eq!(position(0, i32::MAX, 100, 101), 68);
+        assert_eq!(position(0, -i32::MAX, 100, 103), 40);
+        assert_eq!(part_one(&robots, &Config::default()).unwrap(), 0);
+        assert!(part_two(&robots, &Config::default()).is_err());
+    }
+
+    #[test]
+    #[ignore = "needs the real input and the answers file"]
+    fn real_input() {
+        let answers = Answers::load(&FILES).unwrap();
+        let robots = parse(input::real(&FILES).unwrap()).unwrap();
+        answers.check(1, part_one(&robots, &Config::default()).unwrap());
+        answers.check(2, part_two(&robots, &Config::default()).unwrap());
+    }
+}
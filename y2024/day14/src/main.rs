@@ -0,0 +1,29 @@
+use std::error::Error;
+use std::io::Write;
+use std::process::ExitCode;
+
+use common::input::{self, InputArgs};
+use common::output::Report;
+use y2024_day14::{draw_tree, parse, part_one, part_two, Config, FILES};
+
+fn main() -> ExitCode {
+    common::trace::init();
+    common::error::report(run())
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let args = InputArgs::from_env()?;
+    let config = Config::load(&args)?;
+    let mut report = Report::new(&FILES, &args);
+    let reader = input::select(&FILES, &args)?;
+    let robots = report.parse(|| parse(reader))?;
+
+    report.try_part(1, "Result (part one)", || part_one(&robots, &config))?;
+    let time = report.try_part(2, "Result (part two)", || part_two(&robots, &config))?;
+
+    let mut visualization = report.visualization()?;
+    writeln!(visualization, "Tree (part two):")?;
+    write!(visualization, "{}", draw_tree(&robots, time, &config))?;
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use common::random::Rng;
+
+/// Warehouse of 'width' by 'height' cells surrounded by walls, followed by 'moves' moves of the
+/// robot on lines of 70 moves.
+///
+/// Inside, about one cell in twenty is a wall and one in five a box, and a single cell holds the robot.
+pub fn input(seed: u64, width: usize, height: usize, moves: usize) -> String {
+    assert!(
+        width >= 3 && height >= 3,
+        "The warehouse needs a cell inside its walls"
+    );
+    let mut rng = Rng::new(seed);
+
+    let mut cells: Vec<Vec<char>> = (0..height)
+        .map(|row| {
+            (0..width)
+                .map(|col| {
+                    if row == 0 || col == 0 || row == height - 1 || col == width - 1 {
+                        '#'
+                    } else {
+                        match rng.below(20) {
+                            0 => '#',
+                            1..=4 => 'O',
+                            _ => '.',
+                        }
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    cells[1 + rng.below(height - 2)][1 + rng.below(width - 2)] = '@';
+
+    let mut text: String = cells
+        .iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect();
+    text.push('\n');
+
+    let moves: Vec<char> = (0..moves)
+        .map(|_| *rng.choose(&['^', '>', 'v', '<']))
+        .collect();
+    for line in moves.chunks(70) {
+        text.extend(line);
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part_one, part_two};
+
+    #[test]
+    fn parses_and_solves() {
+        let text = input(15, 40, 30, 5000);
+        assert_eq!(text.matches('@').count(), 1);
+        let warehouse = parse(text.as_bytes()).unwrap();
+        assert!(part_one(&warehouse).unwrap() > 0);
+        assert!(part_two(&warehouse).unwrap() > 0);
+    }
+}
@@ -0,0 +1,467 @@
+use std::fmt;
+use std::io::BufRead;
+use std::iter;
+
+use animation::Frame;
+use common::error::{self, Error, Result};
+use common::input::DayFiles;
+use common::lint;
+use common::solution::Solution;
+use grid::{Grid, Position};
+use tracing::debug;
+
+pub mod generate;
+
+/// Puzzle input files of the day
+pub const FILES: DayFiles = common::day_files!(2024, 15, "input_test.txt");
+
+/// Map of the warehouse, the robot's moves and the robot's starting (row, column) position
+pub struct Warehouse {
+    pub map: Grid<Cell>,
+    pub actions: Vec<char>,
+    pub pos_robot: Position,
+}
+
+/// Parses the warehouse map followed by the list of moves
+pub fn parse(reader: impl BufRead) -> Result<Warehouse> {
+    let mut pos_robot = None;
+    let mut error = None;
+    let lines = error::lines(FILES.name, reader).collect::<Result<Vec<_>>>()?;
+
+    // The map ends at the first blank line, the moves follow
+    let map_lines = lines.iter().map(|line| line.text.trim_end()).take_while(|line| !line.is_empty());
+    let map = Grid::parse_lines(map_lines, |(row, col), c| match c {
+        '@' if pos_robot.is_none() => {
+            pos_robot = Some((row, col));
+            Cell::Robot
+        }
+        'O' => Cell::Box,
+        '.' => Cell::Empty,
+        '#' => Cell::Wall,
+        _ => {
+            let message = if c == '@' { "second robot" } else { "invalid cell" };
+            error.get_or_insert_with(|| lines[row].error_at(col + 1, &c.to_string(), message));
+            Cell::Wall
+        }
+    })
+    .map_err(|e| lines[e.row].wrong_width(e.expected))?;
+
+    if let Some(e) = error {
+        return Err(e);
+    }
+
+    let mut actions = Vec::new();
+    for line in lines.iter().skip(map.height()) {
+        for (column, c) in line.chars() {
+            match c {
+                '<' | '>' | 'v' | '^' => actions.push(c),
+                c if c.is_whitespace() => {}
+                _ => return Err(line.error_at(column, &c.to_string(), "invalid move")),
+            }
+        }
+    }
+
+    Ok(Warehouse {
+        map,
+        actions,
+        pos_robot: pos_robot.ok_or_else(|| Error::invalid(FILES.name, "the map has no robot"))?,
+    })
+}
+
+/// Checks that the map is a rectangle surrounded by walls holding a single robot, followed by a
+/// blank line and the moves, reporting every problem found
+pub fn check(reader: impl BufRead) -> Vec<Error> {
+    lint::check(FILES.name, reader, |lines| {
+        let map_height = lines.iter().take_while(|line| !line.text.trim_end().is_empty()).count();
+        let (map, moves) = lines.split_at(map_height);
+
+        let mut problems = lint::grid(FILES.name, map, |c| "#.O@".contains(c), "'#', '.', 'O' or '@'");
+        problems.extend(lint::exactly_one(FILES.name, map, |c| c == '@', "robot"));
+        for (row, line) in map.iter().enumerate() {
+            let last = line.text.chars().count();
+            problems.extend(
+                line.chars()
+                    .filter(|&(column, c)| {
+                        let border = row == 0 || row + 1 == map.len() || column == 1 || column == last;
+                        border && c != '#'
+                    })
+                    .map(|(column, c)| line.error_at(column, &c.to_string(), "expected '#' on the border of the map")),
+            );
+        }
+        if moves.iter().all(|line| line.text.trim().is_empty()) {
+            problems.push(Error::invalid(FILES.name, "the robot has no moves"));
+        }
+        for line in moves {
+            problems.extend(
+                line.chars()
+                    .filter(|&(_, c)| !"<>^v".contains(c) && !c.is_whitespace())
+                    .map(|(column, c)| line.error_at(column, &c.to_string(), "invalid move")),
+            );
+        }
+        problems
+    })
+}
+
+fn get_deltas(label: &char) -> Result<(isize, isize)> {
+    match label {
+        // Move left
+        '<' => Ok((0, -1)),
+        // Move right
+        '>' => Ok((0, 1)),
+        // Move up
+        '^' => Ok((-1, 0)),
+        // Move down
+        'v' => Ok((1, 0)),
+        // Invalid action label
+        c => Err(Error::invalid(FILES.name, format!("invalid action '{}'", c))),
+    }
+}
+
+pub fn part_one(warehouse: &Warehouse) -> Result<i32> {
+    let map = &mut warehouse.map.clone();
+    let mut pos_robot = warehouse.pos_robot;
+
+    for action in &warehouse.actions {
+        pos_robot = push(map, pos_robot, action)?;
+    }
+
+    let res: usize = map
+        .iter()
+        .filter(|(_, cell)| matches!(cell, Cell::Box))
+        .map(|((i, j), _)| i * 100 + j)
+        .sum();
+    Ok(res as i32)
+}
+
+/// Moves the robot one cell along 'action', pushing the boxes in front of it unless they are blocked by a wall. Returns the new position of the robot
+fn push(map: &mut Grid<Cell>, pos_robot: Position, action: &char) -> Result<Position> {
+    let delta = get_deltas(action)?;
+    match push_line(map, pos_robot, delta) {
+        Some(position) => Ok(position),
+        None => {
+            debug!(row = pos_robot.0, column = pos_robot.1, action = %action, "push rejected, blocked by a wall");
+            Ok(pos_robot)
+        }
+    }
+}
+
+/// Moves the robot one cell along 'delta' with the boxes in a line in front of it, returning the
+/// new position of the robot, or None if they are blocked by a wall or by the edge of the map
+fn push_line(map: &mut Grid<Cell>, pos_robot: Position, delta: (isize, isize)) -> Option<Position> {
+    // The robot, the boxes it pushes and the empty cell they move into
+    let mut line = vec![pos_robot];
+    loop {
+        let next = map.step(*line.last()?, delta)?;
+        line.push(next);
+        match map[next] {
+            Cell::Box | Cell::LeftBox | Cell::RightBox => {}
+            Cell::Empty => break,
+            Cell::Wall | Cell::Robot => return None,
+        }
+    }
+
+    for k in (1..line.len()).rev() {
+        map[line[k]] = map[line[k - 1]];
+    }
+    map[pos_robot] = Cell::Empty;
+    Some(line[1])
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Cell {
+    Robot,
+    Box,
+    LeftBox,
+    RightBox,
+    Wall,
+    Empty,
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Cell::Empty => '.',
+            Cell::LeftBox => '[',
+            Cell::RightBox => ']',
+            Cell::Box => 'O',
+            Cell::Robot => '@',
+            Cell::Wall => '#',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+fn move_vertically(
+    map: &Grid<Cell>,
+    pos_robot: Position,
+    action: char,
+) -> Option<Grid<Cell>> {
+    let mut map = map.clone();
+    let (mut r, c) = pos_robot;
+
+    let d_r = if action == 'v' { 1 } else { -1 };
+
+    let mut boxes = vec![];
+
+
+    // Leaving the map counts as facing a wall
+    let cell = map.step((r, c), (d_r, 0)).map(|position| (position, map[position]));
+
+    match cell {
+        Some(((new_r, _), Cell::Empty)) => {
+            map[(r, c)] = Cell::Empty;
+            map[(new_r, c)] = Cell::Robot;
+            return Some(map);
+        }
+        Some(((new_r, _), Cell::LeftBox)) => {
+            map[(r, c)] = Cell::Empty;
+            map[(new_r, c)] = Cell::Robot;
+            map[(new_r, c + 1)] = Cell::Empty;
+            boxes.push(c);
+            r = new_r;
+        }
+        Some(((new_r, _), Cell::RightBox)) => {
+            map[(r, c)] = Cell::Empty;
+            map[(new_r, c)] = Cell::Robot;
+            map[(new_r, c - 1)] = Cell::Empty;
+            boxes.push(c - 1);
+            r = new_r;
+        }
+        _ => {
+            debug!(row = r, column = c, action = %action, "push rejected, the robot faces a wall");
+            return None;
+        }
+    }
+
+
+    let mut stop = false;
+    while !boxes.is_empty() && !stop {
+        stop = true;
+
+        let Some((new_r, _)) = map.step((r, 0), (d_r, 0)) else {
+            debug!(row = r, action = %action, "push rejected, the boxes would leave the map");
+            return None;
+        };
+
+        let old_boxes = boxes.clone();
+        boxes.clear();
+
+        // Every box spans the columns 'start' and 'start + 1', and the halves of a box are side by side
+        for start in old_boxes {
+            match (map[(new_r, start)], map[(new_r, start + 1)]) {
+
+                // Two empty blocks, we can just move up
+                (Cell::Empty, Cell::Empty) => {
+                    // Move current block
+                    map[(new_r, start)] = Cell::LeftBox;
+                    map[(new_r, start + 1)] = Cell::RightBox;
+                }
+
+                // Next block is alligned, push up and continue
+                (Cell::LeftBox, Cell::RightBox) => {
+                    // Move current block
+                    map[(new_r, start)] = Cell::LeftBox;
+                    map[(new_r, start + 1)] = Cell::RightBox;
+
+                    // Add alligned block in blocks to move
+                    boxes.push(start);
+                    stop = false;
+                }
+
+                // Next block is not aligned
+                (Cell::Empty | Cell::RightBox, Cell::Empty | Cell::LeftBox) => {
+
+                    // If we have a left block
+                    if matches!(map[(new_r, start)], Cell::RightBox){
+                        // Push left block and clean remaining space
+                        boxes.push(start - 1);
+                        map[(new_r, start - 1)] = Cell::Empty;
+                    }
+                    
+                    // If we have a right block
+                    if matches!(map[(new_r, start+1)], Cell::LeftBox){
+                        // Push right block and clean remaining space
+                        boxes.push(start + 1);
+                        map[(new_r, start + 2)] = Cell::Empty;
+                    }
+
+                    // Move current block
+                    map[(new_r, start)] = Cell::LeftBox;
+                    map[(new_r, start + 1)] = Cell::RightBox;
+
+                    stop = false;
+                }
+
+                // Next block contains walls
+                (_, _) => {
+                    debug!(row = new_r, column = start, action = %action, "push rejected, a box is blocked by a wall");
+                    return None;
+                }
+            }
+        }
+
+        r = new_r;
+    }
+
+    if stop {
+        return Some(map)
+    }
+ 
+    None
+}
+
+pub fn part_two(warehouse: &Warehouse) -> Result<usize> {
+    let map = &mut scale_up(&warehouse.map);
+    let mut pos_robot = (warehouse.pos_robot.0, 2 * warehouse.pos_robot.1);
+
+    for action in warehouse.actions.iter() {
+        pos_robot = push_wide(map, pos_robot, action)?;
+    }
+
+
+    let res = map
+        .iter()
+        .filter(|(_, cell)| matches!(cell, Cell::LeftBox))
+        .map(|((i, j), _)| i * 100 + j)
+        .sum();
+    Ok(res)
+}
+
+/// Same as 'push' in the scaled up warehouse, where vertical pushes can move a whole pile of wide boxes
+fn push_wide(map: &mut Grid<Cell>, pos_robot: Position, action: &char) -> Result<Position> {
+    let delta = get_deltas(action)?;
+
+    let moved = match action {
+        '<' | '>' => push_line(map, pos_robot, delta),
+        _ => move_vertically(map, pos_robot, *action).map(|mut new_map| {
+            std::mem::swap(map, &mut new_map);
+            map.step(pos_robot, delta).expect("The robot moved inside the map")
+        }),
+    };
+    Ok(moved.unwrap_or(pos_robot))
+}
+
+/// Frames of the robot pushing the boxes around the warehouse, one per move, in the scaled up warehouse of part two when 'wide'
+pub fn pushes(warehouse: &Warehouse, wide: bool) -> Result<impl Iterator<Item = Frame> + '_> {
+    for action in &warehouse.actions {
+        get_deltas(action)?;
+    }
+
+    type Step = fn(&mut Grid<Cell>, Position, &char) -> Result<Position>;
+    let (mut map, mut pos_robot, step): (_, _, Step) = if wide {
+        (scale_up(&warehouse.map), (warehouse.pos_robot.0, 2 * warehouse.pos_robot.1), push_wide)
+    } else {
+        (warehouse.map.clone(), warehouse.pos_robot, push)
+    };
+
+    let total = warehouse.actions.len();
+    let start = Frame::new(format!("move 0/{}", total), &map);
+    Ok(iter::once(start).chain(warehouse.actions.iter().enumerate().map(move |(index, action)| {
+        pos_robot = step(&mut map, pos_robot, action).expect("The moves are checked beforehand");
+        Frame::new(format!("move {}/{}: {}", index + 1, total, action), &map)
+    })))
+}
+
+/// Scales up the warehouse for part two, doubling the width of every cell
+fn scale_up(map: &Grid<Cell>) -> Grid<Cell> {
+    let cells = map
+        .rows()
+        .flat_map(|row| row.iter())
+        .flat_map(|c| match c {
+            Cell::Empty => [Cell::Empty, Cell::Empty],
+
+            Cell::Box => [Cell::LeftBox, Cell::RightBox],
+
+            Cell::Robot => [Cell::Robot, Cell::Empty],
+
+            _ => [Cell::Wall, Cell::Wall],
+        })
+        .collect();
+    Grid::new(2 * map.width(), map.height(), cells)
+}
+
+/// GPS coordinates of the boxes once the robot is done moving, in the warehouse then in the wide one
+#[derive(Default)]
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Warehouse;
+    type Answer = i64;
+
+    const FILES: &'static DayFiles = &FILES;
+
+    fn check(&self, reader: impl BufRead) -> Vec<Error> {
+        check(reader)
+    }
+
+    fn parse(&self, reader: impl BufRead) -> Result<Warehouse> {
+        parse(reader)
+    }
+
+    fn part_one(&self, input: &Warehouse) -> Result<i64> {
+        Ok(part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &Warehouse) -> Result<i64> {
+        Ok(part_two(input)? as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answers::Answers;
+    use common::input;
+
+    #[test]
+    fn example() {
+        let warehouse = parse(input::example(&FILES).unwrap()).unwrap();
+        assert_eq!(part_one(&warehouse).unwrap(), 10092);
+        assert_eq!(part_two(&warehouse).unwrap(), 9021);
+    }
+
+    #[test]
+    fn pushes_frames() {
+        let warehouse = parse(input::example(&FILES).unwrap()).unwrap();
+        let gps = |picture: &str, cell| -> usize {
+            picture.lines().enumerate()
+                .flat_map(|(i, line)| line.match_indices(cell).map(move |(j, _)| i * 100 + j))
+                .sum()
+        };
+
+        let frames: Vec<Frame> = pushes(&warehouse, false).unwrap().collect();
+        assert_eq!(frames.len(), warehouse.actions.len() + 1);
+        assert_eq!(gps(&frames.last().unwrap().picture, "O"), 10092);
+
+        let last = pushes(&warehouse, true).unwrap().last().unwrap();
+        assert_eq!(gps(&last.picture, "["), 9021);
+    }
+
+    #[test]
+    fn warehouse_without_border_walls() {
+        // Leaving the map counts as hitting a wall
+        let warehouse = parse("@O\n..\n\n^<>>v>^<<\n".as_bytes()).unwrap();
+        assert_eq!(part_one(&warehouse).unwrap(), 1);
+        assert_eq!(part_two(&warehouse).unwrap(), 2);
+    }
+
+    #[test]
+    fn check_reports_missing_border_walls() {
+        let problems = check("@.\n..\n\n^\n".as_bytes());
+        assert_eq!(problems.len(), 4);
+        assert_eq!(
+            problems[0].to_string(),
+            "y2024_day15: line 1, column 1: '@': expected '#' on the border of the map"
+        );
+        assert!(check(input::example(&FILES).unwrap()).is_empty());
+    }
+
+    #[test]
+    #[ignore = "needs the real input and the answers file"]
+    fn real_input() {
+        let answers = Answers::load(&FILES).unwrap();
+        let warehouse = parse(input::real(&FILES).unwrap()).unwrap();
+        answers.check(1, part_one(&warehouse).unwrap());
+        answers.check(2, part_two(&warehouse).unwrap());
+    }
+}
@@ -0,0 +1,23 @@
+use std::error::Error;
+use std::process::ExitCode;
+
+use common::input::{self, InputArgs};
+use common::output::Report;
+use y2024_day15::{parse, part_one, part_two, FILES};
+
+fn main() -> ExitCode {
+    common::trace::init();
+    common::error::report(run())
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let args = InputArgs::from_env()?;
+    let mut report = Report::new(&FILES, &args);
+    let reader = input::select(&FILES, &args)?;
+    let warehouse = report.parse(|| parse(reader))?;
+
+    report.try_part(1, "Result (part one)", || part_one(&warehouse))?;
+    report.try_part(2, "Result (part two)", || part_two(&warehouse))?;
+
+    Ok(())
+}